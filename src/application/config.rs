@@ -1,5 +1,7 @@
 /// The folder in which all resources are stored.
 const DEFAULT_FOLDER_RESOURCE: &str = "resources";
+/// The environment variable overriding the default resource folder.
+pub const ENV_RESOURCE_DIR: &str = "PHYREXIAN_RESOURCE_DIR";
 /// The folder in which all databases are stored.
 const DEFAULT_FOLDER_RESOURCE_DATABASE: &str = "databases";
 /// The folder in which all sets are stored.
@@ -8,57 +10,114 @@ const DEFAULT_FOLDER_RESOURCE_SET: &str = "sets";
 const DEFAULT_DATABASE_NAME: &str = "AllPrintings";
 /// The file extension for a set.
 pub const EXTENSION_SET: &str = "mtgset";
+/// The file extension for the manifest of a set.
+pub const EXTENSION_SET_MANIFEST: &str = "mtgset.sha256";
 /// The file extension of a JSON.
 const EXTENSION_JSON: &str = "json";
 
 use std::{borrow::Borrow, path::PathBuf};
-use super::super::magic::card::CardSet;
+use super::super::magic::card::{CardSet, SetCode};
 
-pub struct Configuration {}
+/// The configuration of the locations the application stores its resources at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Configuration {
+    resource_path: PathBuf,
+}
 
 impl Configuration {
 
+    /// Creates a configuration storing all resources in the specified folder.
+    ///
+    /// # Parameters
+    ///
+    /// * base - the path to the resource folder
+    pub fn with_base<T: Into<PathBuf>>(base: T) -> Self {
+        Configuration {
+            resource_path: base.into(),
+        }
+    }
+
     /// The path to the resource folder.
-    pub fn resource_path() -> PathBuf {
-        PathBuf::from(DEFAULT_FOLDER_RESOURCE)
+    pub fn resource_path(&self) -> PathBuf {
+        self.resource_path.clone()
     }
 
     /// The path to the database folder.
-    pub fn database_path() -> PathBuf {
-        let mut path = Configuration::resource_path();
+    pub fn database_path(&self) -> PathBuf {
+        let mut path = self.resource_path();
         path.push(DEFAULT_FOLDER_RESOURCE_DATABASE);
         path
     }
 
     /// The path to the database for importing existing information
     /// and conversion into an internal format.
-    pub fn database_import_path() -> PathBuf {
-        let mut path = Configuration::database_path();
+    pub fn database_import_path(&self) -> PathBuf {
+        let mut path = self.database_path();
         path.push(DEFAULT_DATABASE_NAME);
         path.set_extension(EXTENSION_JSON);
         path
     }
 
     /// The path to the folder containing all `Set`s.
-    pub fn set_path() -> PathBuf {
-        let mut path = Configuration::resource_path();
+    pub fn set_path(&self) -> PathBuf {
+        let mut path = self.resource_path();
         path.push(DEFAULT_FOLDER_RESOURCE_SET);
         path
     }
 
-    /// The path to the specified `Set`.
+    /// The path to the specified `Set`. The file is named after the normalised,
+    /// uppercase code of the set.
     ///
     /// # Parameters
     ///
     /// * set - the set to get the path to
-    pub fn set_file_path<T: Borrow<CardSet>>(set: T) -> PathBuf {
-        let mut path = Configuration::set_path();
-        path.push(set.borrow().code());
+    pub fn set_file_path<T: Borrow<CardSet>>(&self, set: T) -> PathBuf {
+        self.set_file_path_by_code(set.borrow().code().as_str())
+    }
+
+    /// The path to the `Set` with the specified code. The code is normalised to uppercase.
+    ///
+    /// # Parameters
+    ///
+    /// * code - the code of the set to get the path to
+    pub fn set_file_path_by_code(&self, code: &str) -> PathBuf {
+        let mut path = self.set_path();
+        path.push(SetCode::from(code).as_str());
         path.set_extension(EXTENSION_SET);
         path
     }
+
+    /// The path to the manifest of the `Set` with the specified code. The code is
+    /// normalised to uppercase.
+    ///
+    /// # Parameters
+    ///
+    /// * code - the code of the set to get the manifest path to
+    pub fn set_manifest_file_path_by_code(&self, code: &str) -> PathBuf {
+        let mut path = self.set_path();
+        path.push(SetCode::from(code).as_str());
+        path.set_extension(EXTENSION_SET_MANIFEST);
+        path
+    }
     
 }
 
+impl Default for Configuration {
+    /// Creates a configuration storing all resources in the folder specified by the
+    /// `PHYREXIAN_RESOURCE_DIR` environment variable or in `resources` if the variable
+    /// is not set or empty.
+    fn default() -> Self {
+        Configuration::with_base(default_resource_path())
+    }
+}
+
+/// Returns the default path to the resource folder.
+fn default_resource_path() -> PathBuf {
+    std::env::var_os(ENV_RESOURCE_DIR)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_FOLDER_RESOURCE))
+}
+
 #[cfg(test)]
 mod test;
\ No newline at end of file
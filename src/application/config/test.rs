@@ -2,11 +2,16 @@ use super::*;
 use super::super::super::magic::card::CardSetBuilder;
 use super::super::super::magic::language::LocalisedString;
 use chrono::NaiveDate;
+use parking_lot::{const_mutex, Mutex};
+
+/// Serialises all tests depending on the process wide environment.
+static ENVIRONMENT_LOCK: Mutex<()> = const_mutex(());
 
 #[test]
 /// Tests if the `resource_path` function returns the correct path.
 fn test_resource_path() {
-    let p = Configuration::resource_path();
+    let _lock = ENVIRONMENT_LOCK.lock();
+    let p = Configuration::default().resource_path();
     assert_eq!(p.to_str().unwrap(), "resources");
     assert!(p.is_relative());
 }
@@ -14,7 +19,8 @@ fn test_resource_path() {
 #[test]
 /// Tests if the `database_path` function returns the correct path.
 fn test_database_path() {
-    let p = Configuration::database_path();
+    let _lock = ENVIRONMENT_LOCK.lock();
+    let p = Configuration::default().database_path();
     assert_eq!(p.to_str().unwrap(), "resources/databases");
     assert!(p.is_relative());
 }
@@ -22,7 +28,8 @@ fn test_database_path() {
 #[test]
 /// Tests if the `import_database_path` function returns the correct path.
 fn test_database_import_path() {
-    let p = Configuration::database_import_path();
+    let _lock = ENVIRONMENT_LOCK.lock();
+    let p = Configuration::default().database_import_path();
     assert_eq!(p.to_str().unwrap(), "resources/databases/AllPrintings.json");
     assert!(p.is_relative());
 }
@@ -30,7 +37,8 @@ fn test_database_import_path() {
 #[test]
 /// Tests if the `set_path` function returns the correct path.
 fn test_set_path() {
-    let p = Configuration::set_path();
+    let _lock = ENVIRONMENT_LOCK.lock();
+    let p = Configuration::default().set_path();
     assert_eq!(p.to_str().unwrap(), "resources/sets");
     assert!(p.is_relative());
 }
@@ -39,17 +47,71 @@ fn test_set_path() {
 /// Tests if the `set_file_path` function returns the correct path.
 fn test_set_file_path() {
     let s = set_with_code("TEST");
-    let p = Configuration::set_file_path(s);
+    let _lock = ENVIRONMENT_LOCK.lock();
+    let p = Configuration::default().set_file_path(s);
     assert_eq!(p.to_str().unwrap(), "resources/sets/TEST.mtgset");
     assert!(p.is_relative());
+    let p = Configuration::default().set_file_path(set_with_code("mh2"));
+    assert_eq!(p.to_str().unwrap(), "resources/sets/MH2.mtgset");
+}
+
+#[test]
+/// Tests if the `set_manifest_file_path_by_code` function returns the correct path.
+fn test_set_manifest_file_path() {
+    let c = Configuration::with_base(PathBuf::from("/custom/base"));
+    assert_eq!(
+        c.set_file_path_by_code("TEST").to_str().unwrap(),
+        "/custom/base/sets/TEST.mtgset"
+    );
+    assert_eq!(
+        c.set_manifest_file_path_by_code("TEST").to_str().unwrap(),
+        "/custom/base/sets/TEST.mtgset.sha256"
+    );
+    assert_eq!(
+        c.set_manifest_file_path_by_code("test").to_str().unwrap(),
+        "/custom/base/sets/TEST.mtgset.sha256"
+    );
+}
+
+#[test]
+/// Tests if all paths are derived from a custom resource folder.
+fn test_custom_base() {
+    let c = Configuration::with_base(PathBuf::from("/custom/base"));
+    assert_eq!(c.resource_path().to_str().unwrap(), "/custom/base");
+    assert_eq!(c.database_path().to_str().unwrap(), "/custom/base/databases");
+    assert_eq!(
+        c.database_import_path().to_str().unwrap(),
+        "/custom/base/databases/AllPrintings.json"
+    );
+    assert_eq!(c.set_path().to_str().unwrap(), "/custom/base/sets");
+    assert_eq!(
+        c.set_file_path(set_with_code("TEST")).to_str().unwrap(),
+        "/custom/base/sets/TEST.mtgset"
+    );
+    assert!(c.set_path().is_absolute());
+}
+
+#[test]
+/// Tests if the resource folder can be overridden by the environment.
+fn test_resource_path_environment() {
+    let _lock = ENVIRONMENT_LOCK.lock();
+    std::env::set_var(ENV_RESOURCE_DIR, "/environment/resources");
+    let c = Configuration::default();
+    std::env::set_var(ENV_RESOURCE_DIR, "");
+    let empty = Configuration::default();
+    std::env::remove_var(ENV_RESOURCE_DIR);
+    assert_eq!(c.resource_path().to_str().unwrap(), "/environment/resources");
+    assert_eq!(c.set_path().to_str().unwrap(), "/environment/resources/sets");
+    assert_eq!(empty.resource_path().to_str().unwrap(), "resources");
+    assert_eq!(Configuration::default().resource_path().to_str().unwrap(), "resources");
 }
 
 fn set_with_code(code: &str) -> CardSet {
     let mut set_builder = CardSetBuilder::default();
     set_builder
         .code(code.to_string())
-        .keyrune("".to_string())
+        .keyrune("test".to_string())
         .name(LocalisedString::new("Test"))
-        .release_date(NaiveDate::from_ymd(2000, 1, 1));
+        .release_date(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
     set_builder.build().unwrap()
 }
\ No newline at end of file
@@ -1,6 +1,11 @@
 pub mod card;
+pub mod collection;
 pub mod colour;
+pub mod deck;
+pub mod format;
+pub mod import;
 pub mod language;
 pub mod legality;
 pub mod physical_card;
 pub mod rarity;
+pub mod type_line;
@@ -1,21 +1,209 @@
 //! The 'card' module provides structures for card classification.
 
 use crate::application::error::PhyrexianError;
+use crate::utility::{self, natural_cmp};
 
 use super::super::application::config::Configuration;
-use super::colour::{ColourSet, ManaCost};
+use super::colour::{Colour, ColourSet, Mana, ManaCost};
+use super::format::Format;
 use super::language::LocalisedString;
 use super::legality::Legality;
 use super::rarity::Rarity;
-use chrono::NaiveDate;
+use super::type_line::TypeLine;
+use chrono::{Months, NaiveDate};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::borrow::Borrow;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt;
 use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Write};
+use std::path::Path;
 use uuid::Uuid;
 
+/// The number of months a set is assumed to be part of the Standard format after its release.
+pub const STANDARD_ROTATION_MONTHS: u32 = 24;
+
+/// The keyrune code of the generic set icon.
+pub const DEFAULT_KEYRUNE: &str = "default";
+
+// The literal representation of all the supported set types as used by MTGJSON.
+const SET_TYPE_ALCHEMY: &str = "alchemy";
+const SET_TYPE_ARCHENEMY: &str = "archenemy";
+const SET_TYPE_BOX: &str = "box";
+const SET_TYPE_COMMANDER: &str = "commander";
+const SET_TYPE_CORE: &str = "core";
+const SET_TYPE_DRAFT_INNOVATION: &str = "draft_innovation";
+const SET_TYPE_DUEL_DECK: &str = "duel_deck";
+const SET_TYPE_EXPANSION: &str = "expansion";
+const SET_TYPE_FROM_THE_VAULT: &str = "from_the_vault";
+const SET_TYPE_FUNNY: &str = "funny";
+const SET_TYPE_MASTERPIECE: &str = "masterpiece";
+const SET_TYPE_MASTERS: &str = "masters";
+const SET_TYPE_MEMORABILIA: &str = "memorabilia";
+const SET_TYPE_PLANECHASE: &str = "planechase";
+const SET_TYPE_PREMIUM_DECK: &str = "premium_deck";
+const SET_TYPE_PROMO: &str = "promo";
+const SET_TYPE_STARTER: &str = "starter";
+const SET_TYPE_TOKEN: &str = "token";
+const SET_TYPE_VANGUARD: &str = "vanguard";
+
+/// The type of a [`CardSet`](CardSet), which describes how the set was released.
+/// Set types unknown to this library are kept as [`Other`](SetType::Other) when converted
+/// from a string, only blank strings are rejected.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SetType {
+    Alchemy,
+    Archenemy,
+    Box,
+    Commander,
+    Core,
+    DraftInnovation,
+    DuelDeck,
+    Expansion,
+    FromTheVault,
+    Funny,
+    Masterpiece,
+    Masters,
+    Memorabilia,
+    Planechase,
+    PremiumDeck,
+    Promo,
+    Starter,
+    Token,
+    Vanguard,
+    /// A set type that is not known to this library.
+    Other(String),
+}
+
+impl<'a> From<&'a SetType> for &'a str {
+    fn from(set_type: &'a SetType) -> Self {
+        match set_type {
+            SetType::Alchemy => SET_TYPE_ALCHEMY,
+            SetType::Archenemy => SET_TYPE_ARCHENEMY,
+            SetType::Box => SET_TYPE_BOX,
+            SetType::Commander => SET_TYPE_COMMANDER,
+            SetType::Core => SET_TYPE_CORE,
+            SetType::DraftInnovation => SET_TYPE_DRAFT_INNOVATION,
+            SetType::DuelDeck => SET_TYPE_DUEL_DECK,
+            SetType::Expansion => SET_TYPE_EXPANSION,
+            SetType::FromTheVault => SET_TYPE_FROM_THE_VAULT,
+            SetType::Funny => SET_TYPE_FUNNY,
+            SetType::Masterpiece => SET_TYPE_MASTERPIECE,
+            SetType::Masters => SET_TYPE_MASTERS,
+            SetType::Memorabilia => SET_TYPE_MEMORABILIA,
+            SetType::Planechase => SET_TYPE_PLANECHASE,
+            SetType::PremiumDeck => SET_TYPE_PREMIUM_DECK,
+            SetType::Promo => SET_TYPE_PROMO,
+            SetType::Starter => SET_TYPE_STARTER,
+            SetType::Token => SET_TYPE_TOKEN,
+            SetType::Vanguard => SET_TYPE_VANGUARD,
+            SetType::Other(value) => value,
+        }
+    }
+}
+
+impl TryFrom<&str> for SetType {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let normalised = value.trim().to_lowercase();
+        match normalised.as_str() {
+            "" => Err(format!("{} is not a valid set type.", value)),
+            SET_TYPE_ALCHEMY => Ok(SetType::Alchemy),
+            SET_TYPE_ARCHENEMY => Ok(SetType::Archenemy),
+            SET_TYPE_BOX => Ok(SetType::Box),
+            SET_TYPE_COMMANDER => Ok(SetType::Commander),
+            SET_TYPE_CORE => Ok(SetType::Core),
+            SET_TYPE_DRAFT_INNOVATION => Ok(SetType::DraftInnovation),
+            SET_TYPE_DUEL_DECK => Ok(SetType::DuelDeck),
+            SET_TYPE_EXPANSION => Ok(SetType::Expansion),
+            SET_TYPE_FROM_THE_VAULT => Ok(SetType::FromTheVault),
+            SET_TYPE_FUNNY => Ok(SetType::Funny),
+            SET_TYPE_MASTERPIECE => Ok(SetType::Masterpiece),
+            SET_TYPE_MASTERS => Ok(SetType::Masters),
+            SET_TYPE_MEMORABILIA => Ok(SetType::Memorabilia),
+            SET_TYPE_PLANECHASE => Ok(SetType::Planechase),
+            SET_TYPE_PREMIUM_DECK => Ok(SetType::PremiumDeck),
+            SET_TYPE_PROMO => Ok(SetType::Promo),
+            SET_TYPE_STARTER => Ok(SetType::Starter),
+            SET_TYPE_TOKEN => Ok(SetType::Token),
+            SET_TYPE_VANGUARD => Ok(SetType::Vanguard),
+            _ => Ok(SetType::Other(value.trim().to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for SetType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        SetType::try_from(value.as_str())
+    }
+}
+
+impl fmt::Display for SetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+/// The code uniquely identifying a [`CardSet`](CardSet). Set codes are case insensitive,
+/// so they are normalised to uppercase on construction.
+pub struct SetCode(String);
+
+impl SetCode {
+    /// Returns the normalised code as string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for SetCode {
+    fn from(code: &str) -> Self {
+        SetCode(code.trim().to_uppercase())
+    }
+}
+
+impl From<String> for SetCode {
+    fn from(code: String) -> Self {
+        SetCode::from(code.as_str())
+    }
+}
+
+impl From<SetCode> for String {
+    fn from(code: SetCode) -> Self {
+        code.0
+    }
+}
+
+impl AsRef<str> for SetCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for SetCode {
+    fn eq(&self, other: &str) -> bool {
+        self == &SetCode::from(other)
+    }
+}
+
+impl fmt::Display for SetCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Builder, Clone, Debug, CopyGetters, Getters, Serialize, Deserialize)]
-/// An archetype of a card.
+/// An archetype of a card. Equality and hashing are based on the identity of the card,
+/// which is its [`UUID`](uuid::Uuid), not on its content. Cards can be sorted in printing
+/// order via [`printing_order`](Card::printing_order).
 pub struct Card {
     #[getset(get = "pub")]
     #[builder(setter(into, strip_option), default)]
@@ -31,6 +219,10 @@ pub struct Card {
     /// The border colour of the card.
     border_colour: String,
 
+    #[getset(get = "pub")]
+    /// The full type of the card.
+    card_type: LocalisedString,
+
     #[getset(get = "pub")]
     /// The colour of the card.
     colour: ColourSet,
@@ -55,8 +247,9 @@ pub struct Card {
     loyalty: Option<String>,
 
     #[builder(default)]
-    /// The legality of the card in different formats..
-    legality: HashMap<String, Legality>,
+    /// The legality of the card in different formats. The formats are kept ordered, so
+    /// serialising a card is deterministic.
+    legality: BTreeMap<String, Legality>,
 
     #[getset(get = "pub")]
     #[builder(setter(into, strip_option), default)]
@@ -81,8 +274,9 @@ pub struct Card {
     rarity: Rarity,
 
     #[getset(get = "pub")]
+    #[builder(setter(into))]
     /// The code of the set the card belongs to.
-    set_code: String,
+    set_code: SetCode,
 
     #[getset(get = "pub")]
     #[builder(setter(into, strip_option), default)]
@@ -98,14 +292,10 @@ pub struct Card {
     /// The UUID of the card.
     uuid: Uuid,
 
-    // TODO: Reimplement missing members.
-    // #[getset(get = "pub")]
-    // /// The full type of the card.
-    // card_type: LocalisedString,
-    // #[getset(get = "pub")]
-    // #[builder(default)]
-    // /// UUIDs of card variations.
-    // variations: Vec<Uuid>,
+    #[getset(get = "pub")]
+    #[builder(default)]
+    /// UUIDs of card variations.
+    variations: Vec<Uuid>,
 }
 
 impl Card {
@@ -118,12 +308,246 @@ impl Card {
     pub fn legality<T: Borrow<String>>(&self, format: T) -> Legality {
         self.legality
             .get(format.borrow())
-            .map(|legality| *legality)
+            .copied()
             .unwrap_or(Legality::NotLegal)
     }
+
+    /// Returns the legality of the card in the specified [`Format`](Format). If the
+    /// legality in the specified format is unknown, it is returned as not legal.
+    /// Use [`legality`](Card::legality) for formats without a [`Format`](Format) variant.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - the format to check the legality
+    pub fn legality_in(&self, format: Format) -> Legality {
+        self.legality(Into::<&str>::into(format).to_string())
+    }
+
+    /// Checks if the card is legal in [`Standard`](Format::Standard) at the specified date.
+    /// In addition to the stored legality this takes rotation into account, assuming that
+    /// a set is part of Standard for [`STANDARD_ROTATION_MONTHS`](STANDARD_ROTATION_MONTHS)
+    /// months after its release. This is an approximation, as actual rotations happen
+    /// once a year for multiple sets at once.
+    ///
+    /// # Parameters
+    ///
+    /// * `set_release` - the release date of the set the card is part of
+    /// * `as_of` - the date to check the legality at
+    // `Option::is_none_or` is only available since Rust 1.82.
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn is_standard_legal(&self, set_release: NaiveDate, as_of: NaiveDate) -> bool {
+        let rotation = set_release.checked_add_months(Months::new(STANDARD_ROTATION_MONTHS));
+        self.legality_in(Format::Standard) == Legality::Legal
+            && set_release <= as_of
+            && rotation.map_or(true, |rotation| as_of < rotation)
+    }
+
+    /// Computes the colour identity of the card from its colours, its mana cost and all
+    /// mana symbols in its rules text in the default language.
+    pub fn compute_colour_identity(&self) -> ColourSet {
+        let text_symbols = ManaCost::new(
+            self.text
+                .iter()
+                .flat_map(|text| parse_mana_symbols(text.get_default()))
+                .collect(),
+        );
+        let mut identity = self.colour.clone();
+        for colour in self.mana_cost.iter().chain(Some(&text_symbols)).flat_map(ManaCost::colours) {
+            identity.add(colour);
+        }
+        identity
+    }
+
+    /// Checks if the type of the card in the default language contains the specified
+    /// substring ignoring case.
+    ///
+    /// # Parameters
+    ///
+    /// * `substring` - the type or part of the type to check for
+    pub fn is_type(&self, substring: &str) -> bool {
+        self.card_type
+            .get_default()
+            .to_lowercase()
+            .contains(&substring.to_lowercase())
+    }
+
+    /// Parses the type of the card in the default language into a [`TypeLine`](TypeLine).
+    /// Returns `None` if the type cannot be parsed.
+    pub fn type_line(&self) -> Option<TypeLine> {
+        TypeLine::try_from(self.card_type.get_default()).ok()
+    }
+
+    /// Checks if the card is a land. Returns `false` if the type of the card cannot be
+    /// parsed.
+    pub fn is_land(&self) -> bool {
+        self.type_line().is_some_and(|type_line| type_line.is_land())
+    }
+
+    /// Checks if the card is a basic land. Returns `false` if the type of the card cannot
+    /// be parsed.
+    pub fn is_basic_land(&self) -> bool {
+        self.type_line()
+            .is_some_and(|type_line| type_line.is_basic() && type_line.is_land())
+    }
+
+    /// Returns the power of the card as a number. Returns `None` if the card has no power
+    /// or if the power is not a plain number such as `*` or `1+*`.
+    pub fn power_numeric(&self) -> Option<i32> {
+        parse_numeric(&self.power)
+    }
+
+    /// Returns the toughness of the card as a number. Returns `None` if the card has no
+    /// toughness or if the toughness is not a plain number such as `*` or `1+*`.
+    pub fn toughness_numeric(&self) -> Option<i32> {
+        parse_numeric(&self.toughness)
+    }
+
+    /// Returns the starting loyalty of the card as a number. Returns `None` if the card has
+    /// no loyalty or if the loyalty is not a plain number such as `X`.
+    pub fn loyalty_numeric(&self) -> Option<i32> {
+        parse_numeric(&self.loyalty)
+    }
+
+    /// Returns a key to sort cards by their collector number in printing order. The key
+    /// consists of everything preceding the first digit of the collector number and the
+    /// value of the digits following it, e.g. `("A-", 12)` for `A-12`. Collector numbers
+    /// without digits are keyed with [`i64::MAX`], so they are sorted last.
+    pub fn by_number_key(&self) -> (String, i64) {
+        let digits_start = self.number.find(|c: char| c.is_ascii_digit());
+        let (prefix, rest) = self.number.split_at(digits_start.unwrap_or(self.number.len()));
+        let digits_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let number = rest[..digits_end].parse().unwrap_or(i64::MAX);
+        (prefix.to_string(), number)
+    }
+
+    /// Compares cards in printing order by their collector number as described by
+    /// [`by_number_key`](Card::by_number_key). Cards with the same collector number are
+    /// ordered by their set code and finally by their [`UUID`](uuid::Uuid).
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the card to compare to
+    pub fn printing_order(&self, other: &Card) -> Ordering {
+        self.by_number_key()
+            .cmp(&other.by_number_key())
+            .then_with(|| natural_cmp(&self.number, &other.number))
+            .then_with(|| self.set_code.cmp(&other.set_code))
+            .then_with(|| self.uuid.cmp(&other.uuid))
+    }
+}
+
+impl CardBuilder {
+    /// Builds the [`Card`](Card) like `build`, but names every missing required field
+    /// in the error instead of only the first one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::card::CardBuilder;
+    ///
+    /// let error = CardBuilder::default().number("1".to_string()).build_validated().unwrap_err();
+    /// assert!(format!("{:?}", error).contains("uuid"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GenericError`](PhyrexianError::GenericError) listing all missing fields.
+    pub fn build_validated(&self) -> Result<Card, PhyrexianError> {
+        let required = [
+            ("border_colour", self.border_colour.is_none()),
+            ("card_type", self.card_type.is_none()),
+            ("colour", self.colour.is_none()),
+            ("colour_identity", self.colour_identity.is_none()),
+            ("name", self.name.is_none()),
+            ("number", self.number.is_none()),
+            ("rarity", self.rarity.is_none()),
+            ("set_code", self.set_code.is_none()),
+            ("uuid", self.uuid.is_none()),
+        ];
+        let missing: Vec<&str> = required
+            .iter()
+            .filter(|(_, is_missing)| *is_missing)
+            .map(|(field, _)| *field)
+            .collect();
+        if missing.is_empty() {
+            Ok(self.build()?)
+        } else {
+            Err(PhyrexianError::GenericError(format!(
+                "The card is missing the required fields: {}",
+                missing.join(", ")
+            )))
+        }
+    }
+}
+
+impl PartialEq for Card {
+    fn eq(&self, other: &Self) -> bool {
+        self.uuid == other.uuid
+    }
+}
+
+impl Eq for Card {}
+
+impl Hash for Card {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uuid.hash(state);
+    }
+}
+
+/// Links the specified [`Card`](Card)s as the faces of a single card, so that each of them
+/// references the [`UUID`](uuid::Uuid)s of all the others.
+///
+/// # Parameters
+///
+/// * `cards` - the faces to link
+pub fn link_faces(cards: &mut [Card]) {
+    let uuids: Vec<Uuid> = cards.iter().map(Card::uuid).collect();
+    for card in cards.iter_mut() {
+        card.faces = uuids
+            .iter()
+            .filter(|uuid| **uuid != card.uuid)
+            .copied()
+            .collect();
+    }
+}
+
+/// Returns the hex encoded SHA-256 digest of the specified bytes.
+///
+/// # Parameters
+///
+/// * `bytes` - the bytes to compute the digest of
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Parses an optional card characteristic like power, toughness or loyalty into a number.
+/// Returns `None` if there is no value or if it is not a plain, optionally signed number.
+///
+/// # Parameters
+///
+/// * `value` - the value to parse
+fn parse_numeric(value: &Option<String>) -> Option<i32> {
+    value.as_ref().and_then(|value| value.trim().parse::<i32>().ok())
+}
+
+/// Parses all valid mana symbols like `{G}` or `{W/U}` contained in the text.
+///
+/// # Parameters
+///
+/// * `text` - the text to search for mana symbols
+fn parse_mana_symbols(text: &str) -> Vec<Mana> {
+    text.split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}'))
+        .filter_map(|(symbol, _)| Mana::try_from(format!("{{{}}}", symbol)).ok())
+        .collect()
 }
 
 #[derive(Builder, Clone, Debug, CopyGetters, Getters, Serialize, Deserialize)]
+#[builder(build_fn(validate = "Self::validate"))]
 /// A set of [`Card`](Card)s.
 pub struct CardSet {
     #[getset(get = "pub")]
@@ -136,20 +560,26 @@ pub struct CardSet {
     cards: HashMap<Uuid, Card>,
 
     #[getset(get = "pub")]
+    #[builder(setter(into))]
     // The unique identifier of the set.
-    code: String,
+    code: SetCode,
 
     #[getset(get = "pub")]
     // The name of the set.
     name: LocalisedString,
 
     #[getset(get = "pub")]
-    // The keyrune image code.
+    // The keyrune image code, which consists of lowercase alphanumeric characters.
     keyrune: String,
 
     #[getset(get = "pub")]
     // The release date of the set.
     release_date: NaiveDate,
+
+    #[getset(get = "pub")]
+    #[builder(setter(into, strip_option), default)]
+    // The type of the set if known.
+    set_type: Option<SetType>,
 }
 
 impl CardSet {
@@ -159,14 +589,240 @@ impl CardSet {
         self.cards.insert(card.uuid(), card)
     }
 
+    /// Returns the CSS class displaying the icon of this set in the Keyrune font.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use phyrexian_library::magic::card::CardSetBuilder;
+    /// use phyrexian_library::magic::language::LocalisedString;
+    ///
+    /// let set = CardSetBuilder::default()
+    ///     .code("MH2".to_string())
+    ///     .keyrune("mh2".to_string())
+    ///     .name(LocalisedString::new("Modern Horizons 2"))
+    ///     .release_date(NaiveDate::from_ymd_opt(2021, 6, 18).unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(set.keyrune_css_class(), "ss ss-mh2");
+    /// ```
+    pub fn keyrune_css_class(&self) -> String {
+        format!("ss ss-{}", self.keyrune)
+    }
+
+    /// Returns the [`Card`](Card) with the specified [`UUID`](uuid::Uuid) if it is part of
+    /// this set.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the card
+    pub fn get(&self, uuid: Uuid) -> Option<&Card> {
+        self.cards.get(&uuid)
+    }
+
+    /// Removes the [`Card`](Card) with the specified [`UUID`](uuid::Uuid) from this set
+    /// and returns it if it was part of the set.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the card
+    pub fn remove(&mut self, uuid: Uuid) -> Option<Card> {
+        self.cards.remove(&uuid)
+    }
+
+    /// Returns the other faces of the specified [`Card`](Card) that are part of this set.
+    /// Faces that are not part of this set are skipped.
+    ///
+    /// # Parameters
+    ///
+    /// * `card` - the card to resolve the faces of
+    pub fn resolve_faces(&self, card: &Card) -> Vec<&Card> {
+        card.faces()
+            .iter()
+            .filter_map(|uuid| self.get(*uuid))
+            .collect()
+    }
+
+    /// Returns the variations of the specified [`Card`](Card) that are part of this set.
+    /// Variations that are not part of this set are skipped.
+    ///
+    /// # Parameters
+    ///
+    /// * `card` - the card to resolve the variations of
+    pub fn resolve_variations(&self, card: &Card) -> Vec<&Card> {
+        card.variations()
+            .iter()
+            .filter_map(|uuid| self.get(*uuid))
+            .collect()
+    }
+
     /// Returns all [`Card`]s in this set.
     pub fn cards(&self) -> Vec<&Card> {
         self.cards.values().collect()
     }
 
+    /// Returns all [`Card`]s in this set sorted by their collector number. Collector numbers
+    /// are compared naturally, so `2` precedes `10` and `12` precedes `12a`.
+    pub fn cards_sorted(&self) -> Vec<&Card> {
+        let mut cards = self.cards();
+        cards.sort_by(|a, b| {
+            natural_cmp(a.number(), b.number()).then_with(|| a.uuid().cmp(&b.uuid()))
+        });
+        cards
+    }
+
+    /// Writes a plain text listing of this set with one line per [`Card`](Card) in the
+    /// order of [`cards_sorted`](CardSet::cards_sorted). Each line consists of the collector
+    /// number, the name in the default language and the mana cost in parentheses, which are
+    /// empty if the card has no mana cost, e.g. `12 Llanowar Elves ({G})`.
+    ///
+    /// # Parameters
+    ///
+    /// * `writer` - the writer to write the listing to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn write_text<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for card in self.cards_sorted() {
+            let mana_cost = card
+                .mana_cost()
+                .as_ref()
+                .map(ManaCost::to_string)
+                .unwrap_or_default();
+            writeln!(writer, "{} {} ({})", card.number(), card.name().get_default(), mana_cost)?;
+        }
+        Ok(())
+    }
+
+    /// Returns all [`Card`]s in this set whose artist matches the specified artist ignoring
+    /// case. The cards are sorted like [`cards_sorted`](CardSet::cards_sorted).
+    ///
+    /// # Parameters
+    ///
+    /// * `artist` - the name of the artist
+    pub fn by_artist(&self, artist: &str) -> Vec<&Card> {
+        let artist = artist.to_lowercase();
+        self.cards_sorted()
+            .into_iter()
+            .filter(|card| {
+                card.artist()
+                    .as_ref()
+                    .is_some_and(|card_artist| card_artist.to_lowercase() == artist)
+            })
+            .collect()
+    }
+
+    /// Returns the names of all artists of the [`Card`]s in this set. Names are sorted and
+    /// deduplicated case insensitively, keeping the lexicographically smallest spelling.
+    pub fn artists(&self) -> Vec<String> {
+        let mut artists: BTreeMap<String, &String> = BTreeMap::new();
+        for artist in self.cards.values().filter_map(|card| card.artist().as_ref()) {
+            artists
+                .entry(artist.to_lowercase())
+                .and_modify(|kept| *kept = (*kept).min(artist))
+                .or_insert(artist);
+        }
+        artists.into_values().cloned().collect()
+    }
+
+    /// Returns the combined colour identity of all [`Card`](Card)s in this set.
+    pub fn colour_identity(&self) -> ColourSet {
+        self.cards
+            .values()
+            .flat_map(|card| card.colour_identity())
+            .copied()
+            .collect()
+    }
+
+    /// Returns the number of coloured mana symbols of each [`Colour`](Colour) in the mana
+    /// costs of all [`Card`](Card)s in this set. Hybrid symbols count towards each of their
+    /// colours. Every colour is contained even if none of its symbols is present.
+    pub fn colour_pip_distribution(&self) -> HashMap<Colour, u32> {
+        Colour::all()
+            .iter()
+            .map(|colour| {
+                let pips = self
+                    .cards
+                    .values()
+                    .filter_map(|card| card.mana_cost().as_ref())
+                    .map(|mana_cost| mana_cost.devotion(*colour))
+                    .sum();
+                (*colour, pips)
+            })
+            .collect()
+    }
+
+    /// Returns an overview of the [`Card`](Card)s in this set.
+    pub fn statistics(&self) -> CardSetStatistics {
+        let mut statistics = CardSetStatistics {
+            total_cards: self.cards.len(),
+            rarity_counts: self.rarity_counts().into_iter().collect(),
+            monocoloured_cards: 0,
+            multicoloured_cards: 0,
+            colourless_cards: 0,
+            average_converted_mana_cost: None,
+            colour_identity: self.colour_identity(),
+        };
+        let mut costs = Vec::new();
+        for card in self.cards.values() {
+            if card.colour().is_colourless() {
+                statistics.colourless_cards += 1;
+            } else if card.colour().is_monocoloured() {
+                statistics.monocoloured_cards += 1;
+            } else {
+                statistics.multicoloured_cards += 1;
+            }
+            if let Some(mana_cost) = card.mana_cost().as_ref().filter(|_| !card.is_land()) {
+                costs.push(mana_cost.converted_mana_cost());
+            }
+        }
+        if !costs.is_empty() {
+            statistics.average_converted_mana_cost =
+                Some(costs.iter().sum::<f64>() / costs.len() as f64);
+        }
+        statistics
+    }
+
+    /// Removes duplicate printings from this set, so that only a single [`Card`](Card) per
+    /// name in the default language remains. The card with the highest [`Rarity`](Rarity)
+    /// is kept. Ties are broken by keeping the card with the lowest collector number.
+    /// Returns the number of removed cards.
+    pub fn dedupe_by_name(&mut self) -> usize {
+        let mut representatives: HashMap<String, &Card> = HashMap::new();
+        for card in self.cards_sorted() {
+            let name = card.name().get_default().to_string();
+            match representatives.get(&name) {
+                Some(kept) if kept.rarity() >= card.rarity() => {}
+                _ => {
+                    representatives.insert(name, card);
+                }
+            }
+        }
+        let kept: HashSet<Uuid> = representatives.values().map(|card| card.uuid()).collect();
+        let size = self.cards.len();
+        self.cards.retain(|uuid, _| kept.contains(uuid));
+        size - self.cards.len()
+    }
+
+    /// Returns the number of [`Card`]s in this set per [`Rarity`](Rarity).
+    /// Rarities without any cards are not part of the result.
+    pub fn rarity_counts(&self) -> HashMap<Rarity, usize> {
+        let mut counts = HashMap::new();
+        for card in self.cards.values() {
+            *counts.entry(card.rarity()).or_insert(0) += 1;
+        }
+        counts
+    }
+
     /// Writes this `Set` to a file.
-    pub fn save(&self) -> Result<(), PhyrexianError> {
-        let path = Configuration::set_file_path(self);
+    ///
+    /// # Parameters
+    ///
+    /// * `configuration` - the configuration defining the location of the file
+    pub fn save(&self, configuration: &Configuration) -> Result<(), PhyrexianError> {
+        let path = configuration.set_file_path(self);
         if let Some(parent_path) = path.parent() {
             std::fs::create_dir_all(parent_path)?;
         }
@@ -174,4 +830,135 @@ impl CardSet {
         bincode::serialize_into(file, &self)?;
         Ok(())
     }
+
+    /// Writes this `Set` to a file like [`save`](CardSet::save) and additionally writes
+    /// a manifest file next to it containing the hex encoded SHA-256 digest of the
+    /// serialised set, which allows detecting corrupted files when loading them with
+    /// [`load_verified_by_code`](CardSet::load_verified_by_code).
+    ///
+    /// # Parameters
+    ///
+    /// * `configuration` - the configuration defining the location of the files
+    pub fn save_with_manifest(&self, configuration: &Configuration) -> Result<(), PhyrexianError> {
+        let path = configuration.set_file_path(self);
+        if let Some(parent_path) = path.parent() {
+            std::fs::create_dir_all(parent_path)?;
+        }
+        let bytes = bincode::serialize(&self)?;
+        std::fs::write(path, &bytes)?;
+        std::fs::write(
+            configuration.set_manifest_file_path_by_code(self.code().as_str()),
+            sha256_hex(&bytes),
+        )?;
+        Ok(())
+    }
+
+    /// Reads the `Set` with the specified code as written by
+    /// [`save_with_manifest`](CardSet::save_with_manifest) and verifies it against
+    /// its manifest.
+    ///
+    /// # Parameters
+    ///
+    /// * `code` - the code of the set
+    /// * `configuration` - the configuration defining the location of the files
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConversionError`](PhyrexianError::ConversionError) if the digest of the
+    /// set file does not match its manifest.
+    pub fn load_verified_by_code(
+        code: &str,
+        configuration: &Configuration,
+    ) -> Result<CardSet, PhyrexianError> {
+        let bytes = std::fs::read(configuration.set_file_path_by_code(code))?;
+        let manifest = std::fs::read_to_string(configuration.set_manifest_file_path_by_code(code))?;
+        if manifest.trim() != sha256_hex(&bytes) {
+            return Err(PhyrexianError::ConversionError(format!(
+                "The set {} does not match its manifest.",
+                code
+            )));
+        }
+        Ok(bincode::deserialize(&bytes)?)
+    }
+
+    /// Writes this `Set` to the specified file as human readable JSON.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - the path of the file to write to
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> Result<(), PhyrexianError> {
+        utility::save_json(self, path)
+    }
+
+    /// Reads a `Set` from the specified JSON file as written by
+    /// [`save_json`](CardSet::save_json).
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - the path of the file to read from
+    pub fn load_json<P: AsRef<Path>>(path: P) -> Result<CardSet, PhyrexianError> {
+        utility::load_json(path)
+    }
+}
+
+#[derive(Clone, Debug, CopyGetters, Getters, Serialize)]
+/// An overview of the [`Card`](Card)s in a [`CardSet`](CardSet).
+pub struct CardSetStatistics {
+    #[getset(get_copy = "pub")]
+    /// The number of cards in the set.
+    total_cards: usize,
+
+    #[getset(get = "pub")]
+    /// The number of cards of each rarity present in the set.
+    rarity_counts: BTreeMap<Rarity, usize>,
+
+    #[getset(get_copy = "pub")]
+    /// The number of cards with a single colour.
+    monocoloured_cards: u32,
+
+    #[getset(get_copy = "pub")]
+    /// The number of cards with multiple colours.
+    multicoloured_cards: u32,
+
+    #[getset(get_copy = "pub")]
+    /// The number of colourless cards.
+    colourless_cards: u32,
+
+    #[getset(get_copy = "pub")]
+    /// The average converted mana cost of all cards with a mana cost, which are not lands.
+    /// This is `None` if there are no such cards.
+    average_converted_mana_cost: Option<f64>,
+
+    #[getset(get = "pub")]
+    /// The combined colour identity of all cards.
+    colour_identity: ColourSet,
 }
+
+impl CardSetBuilder {
+    /// Checks if the values set on the builder describe a valid [`CardSet`](CardSet).
+    fn validate(&self) -> Result<(), String> {
+        match &self.keyrune {
+            Some(keyrune) if !is_valid_keyrune(keyrune) => Err(format!(
+                "{} is not a valid keyrune code as it must be lowercase alphanumeric.",
+                keyrune
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks if the keyrune code is not empty and only consists of lowercase ASCII letters
+/// and digits.
+///
+/// # Parameters
+///
+/// * `keyrune` - the keyrune code to check
+pub(crate) fn is_valid_keyrune(keyrune: &str) -> bool {
+    !keyrune.is_empty()
+        && keyrune
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod test;
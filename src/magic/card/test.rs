@@ -0,0 +1,649 @@
+use super::*;
+use crate::magic::colour::Colour;
+use chrono::NaiveDate;
+
+/// Returns an empty `CardSet`.
+fn card_set() -> CardSet {
+    CardSetBuilder::default()
+        .code("TEST".to_string())
+        .keyrune("test".to_string())
+        .name(LocalisedString::new("Test"))
+        .release_date(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap())
+        .build()
+        .unwrap()
+}
+
+/// Returns a builder for a `Card` with all required fields set.
+fn card_builder(uuid: u128) -> CardBuilder {
+    let mut builder = CardBuilder::default();
+    builder
+        .border_colour("black".to_string())
+        .card_type(LocalisedString::new("Creature — Elf Warrior"))
+        .colour(ColourSet::new())
+        .colour_identity(ColourSet::new())
+        .name(LocalisedString::new(format!("Card {}", uuid)))
+        .number(uuid.to_string())
+        .rarity(Rarity::Common)
+        .set_code("TEST".to_string())
+        .uuid(Uuid::from_u128(uuid));
+    builder
+}
+
+#[test]
+/// Tests if the `power_numeric` and `toughness_numeric` methods of `Card` work as expected.
+fn test_power_toughness_numeric() {
+    let card = card_builder(1).power("3").toughness("*").build().unwrap();
+    assert_eq!(card.power_numeric(), Some(3));
+    assert_eq!(card.toughness_numeric(), None);
+    let card = card_builder(2).power("1+*").toughness("+2").build().unwrap();
+    assert_eq!(card.power_numeric(), None);
+    assert_eq!(card.toughness_numeric(), Some(2));
+    let card = card_builder(3).power("-1").build().unwrap();
+    assert_eq!(card.power_numeric(), Some(-1));
+    assert_eq!(card.toughness_numeric(), None);
+}
+
+#[test]
+/// Tests if the `loyalty_numeric` method of `Card` works as expected.
+fn test_loyalty_numeric() {
+    let card = card_builder(1).loyalty("3").build().unwrap();
+    assert_eq!(card.loyalty_numeric(), Some(3));
+    let card = card_builder(2).loyalty("X").build().unwrap();
+    assert_eq!(card.loyalty_numeric(), None);
+    let card = card_builder(3).build().unwrap();
+    assert_eq!(card.loyalty_numeric(), None);
+}
+
+#[test]
+/// Tests if the `link_faces` function works as expected.
+fn test_link_faces() {
+    let mut cards = vec!(
+        card_builder(1).build().unwrap(),
+        card_builder(2).build().unwrap(),
+        card_builder(3).build().unwrap(),
+    );
+    link_faces(&mut cards);
+    assert_eq!(cards[0].faces(), &vec!(Uuid::from_u128(2), Uuid::from_u128(3)));
+    assert_eq!(cards[1].faces(), &vec!(Uuid::from_u128(1), Uuid::from_u128(3)));
+    assert_eq!(cards[2].faces(), &vec!(Uuid::from_u128(1), Uuid::from_u128(2)));
+}
+
+#[test]
+/// Tests if the `card_type` field of `Card` works as expected.
+fn test_card_type() {
+    let card = card_builder(1)
+        .card_type(LocalisedString::new("Legendary Creature — Elf Warrior"))
+        .build()
+        .unwrap();
+    assert_eq!(card.card_type().get_default(), "Legendary Creature — Elf Warrior");
+    assert!(card.is_type("Creature"));
+    assert!(card.is_type("legendary creature"));
+    assert!(card.is_type("ELF"));
+    assert!(!card.is_type("Instant"));
+    let mut builder = card_builder(2);
+    builder.card_type = None;
+    assert!(builder.build().is_err());
+}
+
+#[test]
+/// Tests if the `resolve_variations` method of `CardSet` works as expected.
+fn test_resolve_variations() {
+    let base = card_builder(1)
+        .variations(vec!(Uuid::from_u128(2), Uuid::from_u128(3), Uuid::from_u128(4)))
+        .build()
+        .unwrap();
+    assert!(card_builder(2).build().unwrap().variations().is_empty());
+    let mut set = card_set();
+    set.insert(base.clone());
+    set.insert(card_builder(2).build().unwrap());
+    set.insert(card_builder(3).build().unwrap());
+    let resolved: Vec<Uuid> = set
+        .resolve_variations(&base)
+        .iter()
+        .map(|card| card.uuid())
+        .collect();
+    assert_eq!(resolved, vec!(Uuid::from_u128(2), Uuid::from_u128(3)));
+}
+
+#[test]
+/// Tests if the `resolve_faces` method of `CardSet` works as expected.
+fn test_resolve_faces() {
+    let mut faces = vec!(card_builder(1).build().unwrap(), card_builder(2).build().unwrap());
+    link_faces(&mut faces);
+    let (front, back) = (faces[0].clone(), faces[1].clone());
+    let mut set = card_set();
+    set.insert(front.clone());
+    assert!(set.resolve_faces(&front).is_empty());
+    set.insert(back.clone());
+    let resolved_back = set.resolve_faces(&front);
+    assert_eq!(resolved_back.len(), 1);
+    assert_eq!(resolved_back[0].uuid(), back.uuid());
+    let resolved_front = set.resolve_faces(resolved_back[0]);
+    assert_eq!(resolved_front.len(), 1);
+    assert_eq!(resolved_front[0].uuid(), front.uuid());
+}
+
+#[test]
+/// Tests if the `remove` method of `CardSet` works as expected.
+fn test_remove() {
+    let mut set = card_set();
+    set.insert(card_builder(1).build().unwrap());
+    assert!(set.remove(Uuid::from_u128(2)).is_none());
+    let removed = set.remove(Uuid::from_u128(1)).unwrap();
+    assert_eq!(removed.uuid(), Uuid::from_u128(1));
+    assert!(set.cards().is_empty());
+    assert!(set.get(Uuid::from_u128(1)).is_none());
+}
+
+#[test]
+/// Tests if the `rarity_counts` method of `CardSet` works as expected.
+fn test_rarity_counts() {
+    let mut set = card_set();
+    assert!(set.rarity_counts().is_empty());
+    set.insert(card_builder(1).build().unwrap());
+    set.insert(card_builder(2).build().unwrap());
+    set.insert(card_builder(3).rarity(Rarity::Rare).build().unwrap());
+    set.insert(card_builder(4).rarity(Rarity::Mythic).build().unwrap());
+    set.insert(card_builder(5).build().unwrap());
+    let counts = set.rarity_counts();
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts.get(&Rarity::Common), Some(&3));
+    assert_eq!(counts.get(&Rarity::Rare), Some(&1));
+    assert_eq!(counts.get(&Rarity::Mythic), Some(&1));
+    assert_eq!(counts.get(&Rarity::Uncommon), None);
+}
+
+#[test]
+/// Tests if the `legality_in` method of `Card` works as expected.
+fn test_legality_in() {
+    let mut legality = BTreeMap::new();
+    legality.insert("standard".to_string(), Legality::Banned);
+    legality.insert("commander".to_string(), Legality::Legal);
+    legality.insert("oldschool".to_string(), Legality::Restricted);
+    let card = card_builder(1).legality(legality).build().unwrap();
+    assert_eq!(card.legality_in(Format::Standard), Legality::Banned);
+    assert_eq!(card.legality_in(Format::Commander), Legality::Legal);
+    assert_eq!(card.legality_in(Format::Modern), Legality::NotLegal);
+    assert_eq!(card.legality("oldschool".to_string()), Legality::Restricted);
+    assert_eq!(card.legality("unknown".to_string()), Legality::NotLegal);
+}
+
+#[test]
+/// Tests if the `cards_sorted` method of `CardSet` works as expected.
+fn test_cards_sorted() {
+    let numbers = vec!("100", "12a", "2", "★1", "10", "12", "1", "12b", "011");
+    let mut set = card_set();
+    for (uuid, number) in numbers.into_iter().enumerate() {
+        set.insert(card_builder(uuid as u128).number(number.to_string()).build().unwrap());
+    }
+    let sorted: Vec<&str> = set.cards_sorted().iter().map(|card| card.number().as_str()).collect();
+    assert_eq!(sorted, vec!("1", "2", "10", "011", "12", "12a", "12b", "100", "★1"));
+}
+
+#[test]
+/// Tests if the `is_standard_legal` method of `Card` works as expected.
+fn test_is_standard_legal() {
+    let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+    let mut legality = BTreeMap::new();
+    legality.insert("standard".to_string(), Legality::Legal);
+    let legal = card_builder(1).legality(legality).build().unwrap();
+    let mut legality = BTreeMap::new();
+    legality.insert("standard".to_string(), Legality::Banned);
+    let banned = card_builder(2).legality(legality).build().unwrap();
+    let release = date(2020, 9, 25);
+    assert!(legal.is_standard_legal(release, date(2020, 9, 25)));
+    assert!(legal.is_standard_legal(release, date(2021, 6, 1)));
+    assert!(!legal.is_standard_legal(release, date(2020, 9, 24)));
+    assert!(!legal.is_standard_legal(release, date(2022, 9, 25)));
+    assert!(!legal.is_standard_legal(date(2010, 1, 1), date(2021, 6, 1)));
+    assert!(!banned.is_standard_legal(release, date(2021, 6, 1)));
+    assert!(!card_builder(3).build().unwrap().is_standard_legal(release, date(2021, 6, 1)));
+}
+
+#[test]
+/// Tests if saving a `CardSet` as JSON and loading it again works as expected.
+fn test_save_load_json() {
+    let path = std::env::temp_dir()
+        .join(format!("phyrexian_library_{}_json", std::process::id()))
+        .join("set.json");
+    let mut set = card_set();
+    set.insert(card_builder(1).power("2").build().unwrap());
+    set.insert(card_builder(2).rarity(Rarity::Rare).build().unwrap());
+    set.save_json(&path).unwrap();
+    assert!(std::fs::read_to_string(&path).unwrap().contains("\n  "));
+    let loaded = CardSet::load_json(&path).unwrap();
+    assert_eq!(loaded.code(), set.code());
+    assert_eq!(loaded.name(), set.name());
+    assert_eq!(loaded.release_date(), set.release_date());
+    assert_eq!(loaded.cards().len(), 2);
+    assert_eq!(loaded.get(Uuid::from_u128(1)).unwrap().power(), &Some("2".to_string()));
+    assert_eq!(loaded.get(Uuid::from_u128(2)).unwrap().rarity(), Rarity::Rare);
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    assert!(CardSet::load_json(&path).is_err());
+}
+
+#[test]
+/// Tests if the `build_validated` method of `CardBuilder` names the missing fields.
+fn test_build_validated() {
+    assert!(card_builder(1).build_validated().is_ok());
+    let mut builder = CardBuilder::default();
+    builder
+        .border_colour("black".to_string())
+        .card_type(LocalisedString::new("Instant"))
+        .colour(ColourSet::new())
+        .colour_identity(ColourSet::new())
+        .name(LocalisedString::new("Card"))
+        .number("1".to_string())
+        .rarity(Rarity::Common)
+        .set_code("TEST".to_string());
+    match builder.build_validated() {
+        Err(PhyrexianError::GenericError(message)) => {
+            assert!(message.ends_with(": uuid"));
+        },
+        _ => panic!("Building a card without UUID must fail."),
+    }
+    match CardBuilder::default().build_validated() {
+        Err(PhyrexianError::GenericError(message)) => {
+            assert!(message.contains("border_colour, card_type"));
+            assert!(message.ends_with("set_code, uuid"));
+        },
+        _ => panic!("Building a card without any fields must fail."),
+    }
+}
+
+#[test]
+/// Tests if the `compute_colour_identity` method of `Card` works as expected.
+fn test_compute_colour_identity() {
+    let card = card_builder(1)
+        .mana_cost(ManaCost::try_from("{1}{G}").unwrap())
+        .text(LocalisedString::new("{T}: Add {C}.\n{2}{U}, {T}: Draw a card. {Invalid}"))
+        .build()
+        .unwrap();
+    let identity = card.compute_colour_identity();
+    assert_eq!(identity, ColourSet::simic());
+    let card = card_builder(2)
+        .colour(vec!(Colour::Red).into_iter().collect())
+        .text(LocalisedString::new("Pay {W/P} or {2/B}: Do something."))
+        .build()
+        .unwrap();
+    let identity = card.compute_colour_identity();
+    assert_eq!(identity, vec!(Colour::Red, Colour::White, Colour::Black).into_iter().collect());
+    assert!(card_builder(3).build().unwrap().compute_colour_identity().is_colourless());
+}
+
+#[test]
+/// Tests if serialising the legality of a `Card` is deterministic.
+fn test_legality_serialisation_order() {
+    let mut legality = BTreeMap::new();
+    for format in ["vintage", "commander", "standard", "legacy", "modern", "pauper"] {
+        legality.insert(format.to_string(), Legality::Legal);
+    }
+    let card = card_builder(1).legality(legality).build().unwrap();
+    let first = serde_json::to_string(&card).unwrap();
+    let second = serde_json::to_string(&card.clone()).unwrap();
+    assert_eq!(first, second);
+    let commander = first.find("\"commander\"").unwrap();
+    let legacy = first.find("\"legacy\"").unwrap();
+    let vintage = first.find("\"vintage\"").unwrap();
+    assert!(commander < legacy && legacy < vintage);
+    let loaded: Card = serde_json::from_str(&first).unwrap();
+    assert_eq!(loaded.legality("pauper".to_string()), Legality::Legal);
+    assert_eq!(loaded.legality("brawl".to_string()), Legality::NotLegal);
+}
+
+#[test]
+/// Tests if equality and hashing of `Card` are based on the UUID.
+fn test_card_identity() {
+    let card = card_builder(1).build().unwrap();
+    let changed = card_builder(1).power("3").build().unwrap();
+    let other = card_builder(2).build().unwrap();
+    assert_eq!(card, changed);
+    assert_ne!(card, other);
+    let mut cards = std::collections::HashSet::new();
+    cards.insert(card.clone());
+    cards.insert(card);
+    cards.insert(changed);
+    assert_eq!(cards.len(), 1);
+    cards.insert(other);
+    assert_eq!(cards.len(), 2);
+}
+
+#[test]
+/// Tests if the `dedupe_by_name` method of `CardSet` works as expected.
+fn test_dedupe_by_name() {
+    let named = |uuid: u128, name: &str, number: &str, rarity: Rarity| {
+        card_builder(uuid)
+            .name(LocalisedString::new(name))
+            .number(number.to_string())
+            .rarity(rarity)
+            .build()
+            .unwrap()
+    };
+    let mut set = card_set();
+    set.insert(named(1, "Dragon", "1", Rarity::Rare));
+    set.insert(named(2, "Dragon", "300", Rarity::Mythic));
+    set.insert(named(3, "Elf", "10", Rarity::Common));
+    set.insert(named(4, "Elf", "2", Rarity::Common));
+    set.insert(named(5, "Elf", "2a", Rarity::Common));
+    set.insert(named(6, "Goblin", "5", Rarity::Uncommon));
+    assert_eq!(set.dedupe_by_name(), 3);
+    let mut remaining: Vec<Uuid> = set.cards().iter().map(|card| card.uuid()).collect();
+    remaining.sort();
+    assert_eq!(remaining, vec!(Uuid::from_u128(2), Uuid::from_u128(4), Uuid::from_u128(6)));
+    assert_eq!(set.dedupe_by_name(), 0);
+}
+
+#[test]
+/// Tests if the `by_artist` and `artists` methods of `CardSet` work as expected.
+fn test_artists() {
+    let mut set = card_set();
+    set.insert(card_builder(1).artist("Rebecca Guay").build().unwrap());
+    set.insert(card_builder(2).artist("John Avon").build().unwrap());
+    set.insert(card_builder(3).artist("rebecca guay").build().unwrap());
+    set.insert(card_builder(4).build().unwrap());
+    let by_artist: Vec<Uuid> =
+        set.by_artist("REBECCA GUAY").iter().map(|card| card.uuid()).collect();
+    assert_eq!(by_artist, vec!(Uuid::from_u128(1), Uuid::from_u128(3)));
+    assert_eq!(set.by_artist("John Avon").len(), 1);
+    assert!(set.by_artist("Unknown").is_empty());
+    set.insert(card_builder(5).artist("aleksi briclot").build().unwrap());
+    assert_eq!(set.artists(), vec!(
+        "aleksi briclot".to_string(),
+        "John Avon".to_string(),
+        "Rebecca Guay".to_string(),
+    ));
+}
+
+/// Returns a configuration storing all resources in a new temporary folder.
+fn temporary_configuration(name: &str) -> Configuration {
+    Configuration::with_base(
+        std::env::temp_dir().join(format!("phyrexian_library_{}_{}", std::process::id(), name)),
+    )
+}
+
+#[test]
+/// Tests if saving a `CardSet` with a manifest and loading it again works as expected.
+fn test_save_load_verified() {
+    let configuration = temporary_configuration("manifest");
+    let mut set = card_set();
+    set.insert(card_builder(1).build().unwrap());
+    set.save_with_manifest(&configuration).unwrap();
+    let manifest =
+        std::fs::read_to_string(configuration.set_manifest_file_path_by_code("TEST")).unwrap();
+    assert_eq!(manifest.len(), 64);
+    let loaded = CardSet::load_verified_by_code("TEST", &configuration).unwrap();
+    assert_eq!(loaded.code(), set.code());
+    assert_eq!(loaded.cards().len(), 1);
+    std::fs::remove_dir_all(configuration.resource_path()).unwrap();
+}
+
+#[test]
+/// Tests if loading a tampered `CardSet` fails the verification.
+fn test_load_verified_tampered() {
+    let configuration = temporary_configuration("manifest_tampered");
+    let mut set = card_set();
+    set.insert(card_builder(1).build().unwrap());
+    set.save_with_manifest(&configuration).unwrap();
+    let path = configuration.set_file_path_by_code("TEST");
+    let mut bytes = std::fs::read(&path).unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xff;
+    std::fs::write(&path, bytes).unwrap();
+    match CardSet::load_verified_by_code("TEST", &configuration) {
+        Err(PhyrexianError::ConversionError(message)) => assert!(message.contains("manifest")),
+        other => panic!("Expected a verification error, but got {:?}.", other),
+    }
+    std::fs::remove_dir_all(configuration.resource_path()).unwrap();
+    assert!(CardSet::load_verified_by_code("TEST", &configuration).is_err());
+}
+
+#[test]
+/// Tests if the set type of a `CardSet` can be set via its builder.
+fn test_set_type() {
+    assert_eq!(card_set().set_type(), &None);
+    let set = CardSetBuilder::default()
+        .code("TEST".to_string())
+        .keyrune("test".to_string())
+        .name(LocalisedString::new("Test"))
+        .release_date(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap())
+        .set_type(SetType::Commander)
+        .build()
+        .unwrap();
+    assert_eq!(set.set_type(), &Some(SetType::Commander));
+}
+
+#[test]
+/// Tests if the conversion of a `SetType` from and to a string works as expected.
+fn test_set_type_conversion() {
+    assert_eq!(SetType::try_from("core"), Ok(SetType::Core));
+    assert_eq!(SetType::try_from("Expansion"), Ok(SetType::Expansion));
+    assert_eq!(SetType::try_from("duel_deck"), Ok(SetType::DuelDeck));
+    assert_eq!(SetType::try_from("masters".to_string()), Ok(SetType::Masters));
+    assert_eq!(
+        SetType::try_from("new_type"),
+        Ok(SetType::Other("new_type".to_string()))
+    );
+    assert!(SetType::try_from(" ").is_err());
+    assert_eq!(SetType::Promo.to_string(), "promo");
+    assert_eq!(SetType::FromTheVault.to_string(), "from_the_vault");
+    assert_eq!(SetType::Other("new_type".to_string()).to_string(), "new_type");
+}
+
+#[test]
+/// Tests if the `colour_identity` method of `CardSet` combines the identities of all cards.
+fn test_set_colour_identity() {
+    let mut set = card_set();
+    assert_eq!(set.colour_identity(), ColourSet::new());
+    let white: ColourSet = vec!(Colour::White).into_iter().collect();
+    let black: ColourSet = vec!(Colour::Black).into_iter().collect();
+    set.insert(card_builder(1).colour_identity(white).build().unwrap());
+    set.insert(card_builder(2).colour_identity(black).build().unwrap());
+    assert_eq!(set.colour_identity(), ColourSet::orzhov());
+}
+
+#[test]
+/// Tests if `Card`s are sorted by their collector number in printing order.
+fn test_card_ordering() {
+    let card = |uuid: u128, number: &str, set_code: &str| {
+        card_builder(uuid)
+            .number(number.to_string())
+            .set_code(set_code.to_string())
+            .build()
+            .unwrap()
+    };
+    assert_eq!(card(1, "A-12", "TEST").by_number_key(), ("A-".to_string(), 12));
+    assert_eq!(card(1, "2a", "TEST").by_number_key(), ("".to_string(), 2));
+    assert_eq!(card(1, "★", "TEST").by_number_key(), ("★".to_string(), i64::MAX));
+    let mut cards = [
+        card(1, "1", "TEST"),
+        card(2, "10", "TEST"),
+        card(3, "2", "TEST"),
+        card(4, "2a", "TEST"),
+        card(5, "2", "ABC"),
+    ];
+    cards.sort_by(Card::printing_order);
+    let numbers: Vec<(&str, &str)> = cards
+        .iter()
+        .map(|card| (card.number().as_str(), card.set_code().as_str()))
+        .collect();
+    assert_eq!(numbers, vec!(
+        ("1", "TEST"),
+        ("2", "ABC"),
+        ("2", "TEST"),
+        ("2a", "TEST"),
+        ("10", "TEST"),
+    ));
+}
+
+#[test]
+/// Tests if the `keyrune_css_class` method of `CardSet` works as expected.
+fn test_keyrune_css_class() {
+    let mut builder = CardSetBuilder::default();
+    builder
+        .code("MH2".to_string())
+        .name(LocalisedString::new("Modern Horizons 2"))
+        .release_date(NaiveDate::from_ymd_opt(2021, 6, 18).unwrap());
+    let set = builder.keyrune("mh2".to_string()).build().unwrap();
+    assert_eq!(set.keyrune_css_class(), "ss ss-mh2");
+    assert!(builder.keyrune("MH2".to_string()).build().is_err());
+    assert!(builder.keyrune("mh-2".to_string()).build().is_err());
+    assert!(builder.keyrune("".to_string()).build().is_err());
+}
+
+#[test]
+/// Tests if the `colour_pip_distribution` method of `CardSet` works as expected.
+fn test_colour_pip_distribution() {
+    let mut set = card_set();
+    let mana_cost = |cost: &str| ManaCost::try_from(cost).unwrap();
+    set.insert(card_builder(1).mana_cost(mana_cost("{1}{W}{W}")).build().unwrap());
+    set.insert(card_builder(2).mana_cost(mana_cost("{B}{W/B}")).build().unwrap());
+    set.insert(card_builder(3).mana_cost(mana_cost("{X}{R}{G/P}")).build().unwrap());
+    set.insert(card_builder(4).build().unwrap());
+    let distribution = set.colour_pip_distribution();
+    assert_eq!(distribution.len(), 5);
+    assert_eq!(distribution[&Colour::White], 3);
+    assert_eq!(distribution[&Colour::Blue], 0);
+    assert_eq!(distribution[&Colour::Black], 2);
+    assert_eq!(distribution[&Colour::Red], 1);
+    assert_eq!(distribution[&Colour::Green], 1);
+}
+
+#[test]
+/// Tests if the `statistics` method of `CardSet` works as expected.
+fn test_statistics() {
+    let statistics = card_set().statistics();
+    assert_eq!(statistics.total_cards(), 0);
+    assert!(statistics.rarity_counts().is_empty());
+    assert_eq!(statistics.average_converted_mana_cost(), None);
+    let mut set = card_set();
+    let colours = |colours: Vec<Colour>| -> ColourSet { colours.into_iter().collect() };
+    let mana_cost = |cost: &str| ManaCost::try_from(cost).unwrap();
+    set.insert(
+        card_builder(1)
+            .colour(colours(vec!(Colour::White)))
+            .colour_identity(colours(vec!(Colour::White)))
+            .mana_cost(mana_cost("{1}{W}"))
+            .build()
+            .unwrap(),
+    );
+    set.insert(
+        card_builder(2)
+            .colour(colours(vec!(Colour::Blue, Colour::Red)))
+            .colour_identity(colours(vec!(Colour::Blue, Colour::Red)))
+            .mana_cost(mana_cost("{2}{U}{R}"))
+            .rarity(Rarity::Rare)
+            .build()
+            .unwrap(),
+    );
+    set.insert(card_builder(3).mana_cost(mana_cost("{3}")).build().unwrap());
+    set.insert(
+        card_builder(4)
+            .card_type(LocalisedString::new("Land"))
+            .mana_cost(mana_cost("{5}"))
+            .build()
+            .unwrap(),
+    );
+    set.insert(card_builder(5).rarity(Rarity::Mythic).build().unwrap());
+    let statistics = set.statistics();
+    assert_eq!(statistics.total_cards(), 5);
+    assert_eq!(statistics.rarity_counts().get(&Rarity::Common), Some(&3));
+    assert_eq!(statistics.rarity_counts().get(&Rarity::Rare), Some(&1));
+    assert_eq!(statistics.rarity_counts().get(&Rarity::Mythic), Some(&1));
+    assert_eq!(statistics.rarity_counts().get(&Rarity::Uncommon), None);
+    assert_eq!(statistics.monocoloured_cards(), 1);
+    assert_eq!(statistics.multicoloured_cards(), 1);
+    assert_eq!(statistics.colourless_cards(), 3);
+    assert_eq!(statistics.average_converted_mana_cost(), Some(3.0));
+    assert_eq!(
+        statistics.colour_identity(),
+        &colours(vec!(Colour::White, Colour::Blue, Colour::Red))
+    );
+    let json = serde_json::to_value(&statistics).unwrap();
+    assert_eq!(json["total_cards"], 5);
+}
+
+#[test]
+/// Tests if the `is_land` and `is_basic_land` methods of `Card` work as expected.
+fn test_is_land() {
+    let card = |card_type: &str| {
+        card_builder(1)
+            .card_type(LocalisedString::new(card_type))
+            .build()
+            .unwrap()
+    };
+    let forest = card("Basic Land — Forest");
+    assert!(forest.is_land());
+    assert!(forest.is_basic_land());
+    let nonbasic = card("Land");
+    assert!(nonbasic.is_land());
+    assert!(!nonbasic.is_basic_land());
+    let creature = card("Creature — Elf Warrior");
+    assert!(!creature.is_land());
+    assert!(!creature.is_basic_land());
+    let unparseable = card("Basic");
+    assert_eq!(unparseable.type_line(), None);
+    assert!(!unparseable.is_land());
+    assert!(!unparseable.is_basic_land());
+}
+
+#[test]
+/// Tests if the `write_text` method of `CardSet` works as expected.
+fn test_write_text() {
+    let mut set = card_set();
+    let mana_cost = |cost: &str| ManaCost::try_from(cost).unwrap();
+    set.insert(
+        card_builder(10)
+            .name(LocalisedString::new("Llanowar Elves"))
+            .mana_cost(mana_cost("{G}"))
+            .build()
+            .unwrap(),
+    );
+    set.insert(
+        card_builder(2)
+            .name(LocalisedString::new("Forest"))
+            .card_type(LocalisedString::new("Basic Land — Forest"))
+            .build()
+            .unwrap(),
+    );
+    set.insert(
+        card_builder(1)
+            .name(LocalisedString::new("Counterspell"))
+            .mana_cost(mana_cost("{U}{U}"))
+            .build()
+            .unwrap(),
+    );
+    let mut text = Vec::new();
+    set.write_text(&mut text).unwrap();
+    assert_eq!(
+        String::from_utf8(text).unwrap(),
+        "1 Counterspell ({U}{U})\n2 Forest ()\n10 Llanowar Elves ({G})\n"
+    );
+}
+
+#[test]
+/// Tests if set codes are normalised to uppercase.
+fn test_set_code() {
+    assert_eq!(SetCode::from("mh2"), SetCode::from("MH2"));
+    assert_eq!(SetCode::from(" mh2 ".to_string()).to_string(), "MH2");
+    assert_eq!(&SetCode::from("MH2"), "mh2");
+    let mut set = card_set();
+    set.code = SetCode::from("mh2");
+    assert_eq!(set.code().as_str(), "MH2");
+    let card = card_builder(1).set_code("mh2").build().unwrap();
+    assert_eq!(card.set_code(), set.code());
+    let path = Configuration::with_base(std::path::PathBuf::from("/base")).set_file_path(&set);
+    assert_eq!(path.to_str().unwrap(), "/base/sets/MH2.mtgset");
+    let serialised = serde_json::to_string(set.code()).unwrap();
+    assert_eq!(serialised, "\"MH2\"");
+    let deserialised: SetCode = serde_json::from_str("\"mh2\"").unwrap();
+    assert_eq!(deserialised, SetCode::from("MH2"));
+}
+
+#[test]
+/// Tests if the SHA-256 digest of set files is computed correctly.
+fn test_sha256_hex() {
+    assert_eq!(
+        sha256_hex(b"abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
@@ -0,0 +1,165 @@
+//! The 'collection' module provides structures for managing owned cards.
+
+use super::physical_card::PhysicalCard;
+use crate::application::error::PhyrexianError;
+use crate::utility;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+/// A collection of [`PhysicalCard`](PhysicalCard)s.
+pub struct Collection {
+    // The physical cards that are part of the collection.
+    cards: HashMap<Uuid, PhysicalCard>,
+}
+
+impl Collection {
+    /// Creates a new empty `Collection`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a [`PhysicalCard`](PhysicalCard) to the `Collection`. If a
+    /// [`PhysicalCard`](PhysicalCard) with the same [`UUID`](uuid::Uuid) is present in the
+    /// collection it is removed and returned.
+    pub fn add(&mut self, card: PhysicalCard) -> Option<PhysicalCard> {
+        self.cards.insert(card.uuid(), card)
+    }
+
+    /// Removes the [`PhysicalCard`](PhysicalCard) with the specified [`UUID`](uuid::Uuid)
+    /// from the `Collection` and returns it if present.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the physical card
+    pub fn remove(&mut self, uuid: Uuid) -> Option<PhysicalCard> {
+        self.cards.remove(&uuid)
+    }
+
+    /// Returns the [`PhysicalCard`](PhysicalCard) with the specified [`UUID`](uuid::Uuid)
+    /// if present.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the physical card
+    pub fn get(&self, uuid: Uuid) -> Option<&PhysicalCard> {
+        self.cards.get(&uuid)
+    }
+
+    /// Returns an iterator over all [`PhysicalCard`](PhysicalCard)s in this collection.
+    pub fn iter(&self) -> impl Iterator<Item = &PhysicalCard> {
+        self.cards.values()
+    }
+
+    /// Returns the number of [`PhysicalCard`](PhysicalCard)s in this collection. Use
+    /// [`total_quantity`](Collection::total_quantity) to take their quantities into account.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Checks if this collection does not contain any [`PhysicalCard`](PhysicalCard)s.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Returns the total number of copies in this collection taking the quantity of each
+    /// [`PhysicalCard`](PhysicalCard) into account.
+    pub fn total_quantity(&self) -> u32 {
+        self.iter().map(PhysicalCard::quantity).fold(0, u32::saturating_add)
+    }
+
+    /// Returns all [`PhysicalCard`](PhysicalCard)s in this collection that are physical
+    /// copies of the specified card template ordered by their [`UUID`](uuid::Uuid).
+    ///
+    /// # Parameters
+    ///
+    /// * `template` - the UUID of the card template
+    pub fn copies_of(&self, template: Uuid) -> Vec<&PhysicalCard> {
+        let mut copies: Vec<&PhysicalCard> =
+            self.iter().filter(|card| card.template() == template).collect();
+        copies.sort_by_key(|card| card.uuid());
+        copies
+    }
+
+    /// Returns the number of copies per card template in this collection taking the
+    /// quantity of each [`PhysicalCard`](PhysicalCard) into account.
+    pub fn template_counts(&self) -> HashMap<Uuid, u32> {
+        let mut counts = HashMap::new();
+        for card in self.iter() {
+            let count = counts.entry(card.template()).or_insert(0u32);
+            *count = count.saturating_add(card.quantity());
+        }
+        counts
+    }
+
+    /// Returns the total value of the collection in cents taking the quantity of each
+    /// [`PhysicalCard`](PhysicalCard) into account. Cards without a value are ignored.
+    pub fn total_value_cents(&self) -> u64 {
+        self.iter()
+            .filter_map(|card| {
+                card.value_cents()
+                    .map(|value| value.saturating_mul(u64::from(card.quantity())))
+            })
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Returns all [`PhysicalCard`](PhysicalCard)s in this collection whose card template is
+    /// not present in the other collection ordered by their [`UUID`](uuid::Uuid).
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the collection to compare against
+    pub fn difference(&self, other: &Collection) -> Vec<&PhysicalCard> {
+        let other_templates = other.template_counts();
+        let mut difference: Vec<&PhysicalCard> = self
+            .iter()
+            .filter(|card| !other_templates.contains_key(&card.template()))
+            .collect();
+        difference.sort_by_key(|card| card.uuid());
+        difference
+    }
+
+    /// Returns the number of copies per card template this collection has in excess of the
+    /// other collection. Templates without excess copies are omitted.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the collection to compare against
+    pub fn template_difference(&self, other: &Collection) -> HashMap<Uuid, u32> {
+        let other_templates = other.template_counts();
+        self.template_counts()
+            .into_iter()
+            .filter_map(|(template, count)| {
+                let other_count = other_templates.get(&template).copied().unwrap_or(0);
+                count
+                    .checked_sub(other_count)
+                    .filter(|excess| *excess > 0)
+                    .map(|excess| (template, excess))
+            })
+            .collect()
+    }
+
+    /// Writes this `Collection` to the specified file as human readable JSON.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - the path of the file to write to
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> Result<(), PhyrexianError> {
+        utility::save_json(self, path)
+    }
+
+    /// Reads a `Collection` from the specified JSON file as written by
+    /// [`save_json`](Collection::save_json).
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - the path of the file to read from
+    pub fn load_json<P: AsRef<Path>>(path: P) -> Result<Collection, PhyrexianError> {
+        utility::load_json(path)
+    }
+}
+
+#[cfg(test)]
+mod test;
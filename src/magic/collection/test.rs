@@ -0,0 +1,171 @@
+use super::*;
+use crate::magic::physical_card::PhysicalCardBuilder;
+
+fn physical_card(uuid: u128, template: Uuid) -> PhysicalCard {
+    PhysicalCardBuilder::default()
+        .template(template)
+        .uuid(Uuid::from_u128(uuid))
+        .build()
+        .unwrap()
+}
+
+#[test]
+/// Tests if the `difference` method of `Collection` works as expected.
+fn test_difference() {
+    let shared = Uuid::from_u128(1);
+    let unique = Uuid::from_u128(2);
+    let other_unique = Uuid::from_u128(3);
+    let mut collection = Collection::new();
+    collection.add(physical_card(10, shared));
+    collection.add(physical_card(11, unique));
+    collection.add(physical_card(15, unique));
+    collection.add(physical_card(13, unique));
+    let mut other = Collection::new();
+    other.add(physical_card(20, shared));
+    other.add(physical_card(21, other_unique));
+    let difference: Vec<Uuid> =
+        collection.difference(&other).iter().map(|card| card.uuid()).collect();
+    assert_eq!(difference, vec!(
+        Uuid::from_u128(11),
+        Uuid::from_u128(13),
+        Uuid::from_u128(15),
+    ));
+    let other_difference = other.difference(&collection);
+    assert_eq!(other_difference.len(), 1);
+    assert_eq!(other_difference[0].uuid(), Uuid::from_u128(21));
+}
+
+#[test]
+/// Tests if the `template_difference` method of `Collection` works as expected.
+fn test_template_difference() {
+    let shared = Uuid::from_u128(1);
+    let unique = Uuid::from_u128(2);
+    let mut collection = Collection::new();
+    collection.add(physical_card(10, shared));
+    collection.add(physical_card(11, shared));
+    collection.add(physical_card(12, shared));
+    collection.add(physical_card(13, unique));
+    let mut other = Collection::new();
+    other.add(physical_card(20, shared));
+    let difference = collection.template_difference(&other);
+    assert_eq!(difference.len(), 2);
+    assert_eq!(difference.get(&shared), Some(&2));
+    assert_eq!(difference.get(&unique), Some(&1));
+    assert!(other.template_difference(&collection).is_empty());
+}
+
+#[test]
+/// Tests if the `template_counts` method of `Collection` sums up the quantities.
+fn test_template_counts() {
+    let template = Uuid::from_u128(1);
+    let mut collection = Collection::new();
+    let mut playset = physical_card(10, template);
+    playset.set_quantity(4).unwrap();
+    collection.add(playset);
+    collection.add(physical_card(11, template));
+    collection.add(physical_card(12, Uuid::from_u128(2)));
+    let counts = collection.template_counts();
+    assert_eq!(counts.get(&template), Some(&5));
+    assert_eq!(counts.get(&Uuid::from_u128(2)), Some(&1));
+    // Quantities too large to be represented saturate instead of overflowing.
+    let mut card = physical_card(13, template);
+    card.set_quantity(u32::MAX).unwrap();
+    collection.add(card);
+    assert_eq!(collection.template_counts().get(&template), Some(&u32::MAX));
+    assert_eq!(collection.total_quantity(), u32::MAX);
+    assert!(collection.difference(&collection).is_empty());
+    assert!(collection.template_difference(&collection).is_empty());
+}
+
+#[test]
+/// Tests if the `total_value_cents` method of `Collection` works as expected.
+fn test_total_value_cents() {
+    let mut collection = Collection::new();
+    assert_eq!(collection.total_value_cents(), 0);
+    let mut card = physical_card(10, Uuid::from_u128(1));
+    card.set_value_cents(Some(250));
+    card.set_quantity(3).unwrap();
+    collection.add(card);
+    let mut card = physical_card(11, Uuid::from_u128(2));
+    card.set_value_cents(Some(1000));
+    collection.add(card);
+    collection.add(physical_card(12, Uuid::from_u128(3)));
+    assert_eq!(collection.total_value_cents(), 1750);
+    // Values too large to be represented saturate instead of overflowing.
+    let mut card = physical_card(13, Uuid::from_u128(4));
+    card.set_value_cents(Some(u64::MAX));
+    card.set_quantity(2).unwrap();
+    collection.add(card);
+    assert_eq!(collection.total_value_cents(), u64::MAX);
+}
+
+#[test]
+/// Tests if adding and removing cards from a `Collection` works as expected.
+fn test_add_remove() {
+    let mut collection = Collection::new();
+    assert!(collection.is_empty());
+    assert!(collection.add(physical_card(10, Uuid::from_u128(1))).is_none());
+    assert!(collection.add(physical_card(11, Uuid::from_u128(1))).is_none());
+    assert_eq!(collection.len(), 2);
+    // Adding a card with the same UUID replaces the previous one.
+    let replaced = collection.add(physical_card(11, Uuid::from_u128(2))).unwrap();
+    assert_eq!(replaced.template(), Uuid::from_u128(1));
+    assert_eq!(collection.len(), 2);
+    assert_eq!(collection.get(Uuid::from_u128(11)).unwrap().template(), Uuid::from_u128(2));
+    let removed = collection.remove(Uuid::from_u128(10)).unwrap();
+    assert_eq!(removed.uuid(), Uuid::from_u128(10));
+    assert!(collection.remove(Uuid::from_u128(10)).is_none());
+    assert!(collection.get(Uuid::from_u128(10)).is_none());
+    assert_eq!(collection.len(), 1);
+    assert!(!collection.is_empty());
+}
+
+#[test]
+/// Tests if the `total_quantity` method of `Collection` works as expected.
+fn test_total_quantity() {
+    let mut collection = Collection::new();
+    assert_eq!(collection.total_quantity(), 0);
+    let mut card = physical_card(10, Uuid::from_u128(1));
+    card.set_quantity(4).unwrap();
+    collection.add(card);
+    collection.add(physical_card(11, Uuid::from_u128(2)));
+    assert_eq!(collection.len(), 2);
+    assert_eq!(collection.total_quantity(), 5);
+}
+
+#[test]
+/// Tests if saving a `Collection` as JSON and loading it again works as expected.
+fn test_save_load_json() {
+    let path = std::env::temp_dir()
+        .join(format!("phyrexian_library_{}_collection_json", std::process::id()))
+        .join("collection.json");
+    let mut collection = Collection::new();
+    let mut card = physical_card(10, Uuid::from_u128(1));
+    card.set_quantity(2).unwrap();
+    collection.add(card);
+    collection.add(physical_card(11, Uuid::from_u128(2)));
+    collection.save_json(&path).unwrap();
+    let loaded = Collection::load_json(&path).unwrap();
+    assert_eq!(loaded.len(), 2);
+    assert_eq!(loaded.total_quantity(), 3);
+    assert_eq!(loaded.get(Uuid::from_u128(11)).unwrap().template(), Uuid::from_u128(2));
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    assert!(Collection::load_json(&path).is_err());
+}
+
+#[test]
+/// Tests if the `copies_of` method of `Collection` works as expected.
+fn test_copies_of() {
+    let first = Uuid::from_u128(1);
+    let second = Uuid::from_u128(2);
+    let mut collection = Collection::new();
+    collection.add(physical_card(12, first));
+    collection.add(physical_card(10, first));
+    collection.add(physical_card(11, second));
+    collection.add(physical_card(13, first));
+    let copies: Vec<Uuid> = collection.copies_of(first).iter().map(|card| card.uuid()).collect();
+    assert_eq!(copies, vec!(Uuid::from_u128(10), Uuid::from_u128(12), Uuid::from_u128(13)));
+    let copies: Vec<Uuid> = collection.copies_of(second).iter().map(|card| card.uuid()).collect();
+    assert_eq!(copies, vec!(Uuid::from_u128(11)));
+    assert!(collection.copies_of(Uuid::from_u128(3)).is_empty());
+}
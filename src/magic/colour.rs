@@ -8,7 +8,9 @@ use std::collections::HashSet;
 use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
+use std::str::FromStr;
 
 // The literal representation of all the supported colours.
 const COLOUR_BLACK: &str = "B";
@@ -19,9 +21,11 @@ const COLOUR_WHITE: &str = "W";
 // The literal representation of all the supported types of mana.
 const MANA_COLOURLESS : &str = "C";
 const MANA_MONO_HYBRID: &str = "2/";
+const MANA_COLOUR_HYBRID: &str = "C/";
 const MANA_DUAL_HYBRID: &str = "/";
 const MANA_SNOW: &str = "S";
 const MANA_PHYREXIAN: &str = "/P";
+const MANA_PHYREXIAN_GENERIC: &str = "2/P";
 const MANA_HALF: &str = "H";
 // The literal representation of starting and ending delimeters of mana notation.
 const MANA_SPECIFIER_START: &str = "{";
@@ -30,6 +34,8 @@ const MANA_SPECIFIER_END: &str = "}";
 const GENERIC_MANA_INFINITY: &str = "∞";
 const GENERIC_MANA_HALF: &str = "½";
 const GENERIC_MANA_VARIABLE: [&str; 3] = ["X", "Y", "Z"];
+// The tolerance when comparing converted mana costs.
+const CONVERTED_MANA_COST_EPSILON: f64 = 1e-9;
 
 
 /// The 'Colour' of a Magic product.
@@ -42,6 +48,33 @@ pub enum Colour {
     White,
 }
 
+impl Colour {
+    /// Returns all colours in the conventional WUBRG order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::{Colour, ColourSet};
+    ///
+    /// let colours: ColourSet = Colour::all().into_iter().collect();
+    /// assert_eq!(colours.to_string(), "WUBRG");
+    /// ```
+    pub fn all() -> [Colour; 5] {
+        [Colour::White, Colour::Blue, Colour::Black, Colour::Red, Colour::Green]
+    }
+
+    /// Returns the position of the colour in the conventional WUBRG order.
+    fn wubrg_position(&self) -> u8 {
+        match self {
+            Colour::White => 0,
+            Colour::Blue => 1,
+            Colour::Black => 2,
+            Colour::Red => 3,
+            Colour::Green => 4,
+        }
+    }
+}
+
 impl From<Colour> for &str {
     fn from(colour: Colour) -> Self {
         (&colour).into()
@@ -78,7 +111,7 @@ impl TryFrom<&str> for Colour {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
+        match value.to_uppercase().as_str() {
             COLOUR_BLACK => Ok(Colour::Black),
             COLOUR_BLUE => Ok(Colour::Blue),
             COLOUR_GREEN => Ok(Colour::Green),
@@ -97,6 +130,14 @@ impl TryFrom<String> for Colour {
     }
 }
 
+impl FromStr for Colour {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Colour::try_from(value)
+    }
+}
+
 impl fmt::Display for Colour {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self.into())
@@ -115,6 +156,91 @@ impl ColourSet {
         Self {colours: HashSet::new()}
     }
 
+    /// Creates the set of ['Colour'](phyrexian_library::magic::colour::Colour)s with the
+    /// specified name. This accepts every name the set is displayed with, e.g. `Izzet League`,
+    /// `Sultai` or `Growth`, ignoring case.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - the name of the colour combination
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::ColourSet;
+    ///
+    /// assert_eq!(ColourSet::from_name("Izzet League"), Ok(ColourSet::izzet()));
+    /// assert_eq!(ColourSet::from_name(&ColourSet::golgari().to_string()), Ok(ColourSet::golgari()));
+    /// assert!(ColourSet::from_name("Izzet").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name does not belong to any colour combination.
+    pub fn from_name(name: &str) -> Result<ColourSet, String> {
+        let colours = Colour::all();
+        (0..1u8 << colours.len())
+            .map(|mask| -> ColourSet {
+                colours.iter()
+                    .enumerate()
+                    .filter(|(index, _)| mask & (1 << index) != 0)
+                    .map(|(_, colour)| *colour)
+                    .collect()
+            })
+            .find(|set| set.to_string().eq_ignore_ascii_case(name.trim()))
+            .ok_or(format!("{} is not a valid name of a colour combination.", name))
+    }
+
+    /// Creates the set of the Azorius Senate, which is white and blue.
+    pub fn azorius() -> Self {
+        vec!(Colour::White, Colour::Blue).into_iter().collect()
+    }
+
+    /// Creates the set of the House Dimir, which is blue and black.
+    pub fn dimir() -> Self {
+        vec!(Colour::Blue, Colour::Black).into_iter().collect()
+    }
+
+    /// Creates the set of the Cult of Rakdos, which is black and red.
+    pub fn rakdos() -> Self {
+        vec!(Colour::Black, Colour::Red).into_iter().collect()
+    }
+
+    /// Creates the set of the Gruul Clans, which is red and green.
+    pub fn gruul() -> Self {
+        vec!(Colour::Red, Colour::Green).into_iter().collect()
+    }
+
+    /// Creates the set of the Selesnya Conclave, which is green and white.
+    pub fn selesnya() -> Self {
+        vec!(Colour::Green, Colour::White).into_iter().collect()
+    }
+
+    /// Creates the set of the Orzhov Syndicate, which is white and black.
+    pub fn orzhov() -> Self {
+        vec!(Colour::White, Colour::Black).into_iter().collect()
+    }
+
+    /// Creates the set of the Izzet League, which is blue and red.
+    pub fn izzet() -> Self {
+        vec!(Colour::Blue, Colour::Red).into_iter().collect()
+    }
+
+    /// Creates the set of the Golgari Swarm, which is black and green.
+    pub fn golgari() -> Self {
+        vec!(Colour::Black, Colour::Green).into_iter().collect()
+    }
+
+    /// Creates the set of the Boros Legion, which is red and white.
+    pub fn boros() -> Self {
+        vec!(Colour::Red, Colour::White).into_iter().collect()
+    }
+
+    /// Creates the set of the Simic Combine, which is green and blue.
+    pub fn simic() -> Self {
+        vec!(Colour::Green, Colour::Blue).into_iter().collect()
+    }
+
     /// Checks if the colour set contains the specified
     /// ['Colour'](phyrexian_library::magic::colour::Colour).
     ///
@@ -349,10 +475,12 @@ pub enum Mana {
     Colourless,
     Generic(GenericCost),
     MonoHybrid(Colour),
+    ColourHybrid(Colour),
     DualHybrid(Colour, Colour),
     DualHybridPhyrexian(Colour, Colour),
     Snow,
     Phyrexian(Colour),
+    PhyrexianGeneric,
     Half(Colour),
 }
 
@@ -364,21 +492,66 @@ impl Mana {
             Mana::Colourless => 1.0,
             Mana::Generic(gen) => gen.converted_mana_cost(),
             Mana::MonoHybrid(_) => 2.0,
+            Mana::ColourHybrid(_) => 1.0,
             Mana::DualHybrid(_,_) => 1.0,
             Mana::DualHybridPhyrexian(_,_) => 1.0,
             Mana::Snow => 1.0,
             Mana::Phyrexian(_) => 1.0,
+            Mana::PhyrexianGeneric => 2.0,
             Mana::Half(_) => 0.5,
         }
     }
 
+    /// Checks if the mana symbol is of the specified [`Colour`](Colour). Hybrid mana is
+    /// of every colour it can be paid with, generic, colourless and snow mana are of no
+    /// colour.
+    ///
+    /// # Parameters
+    ///
+    /// * `colour` - the colour to check for
+    pub fn is_of_colour(&self, colour: Colour) -> bool {
+        match self {
+            Mana::Coloured(c)
+            | Mana::MonoHybrid(c)
+            | Mana::ColourHybrid(c)
+            | Mana::Phyrexian(c)
+            | Mana::Half(c) => *c == colour,
+            Mana::DualHybrid(a, b) | Mana::DualHybridPhyrexian(a, b) => {
+                *a == colour || *b == colour
+            },
+            Mana::Colourless | Mana::Generic(_) | Mana::PhyrexianGeneric | Mana::Snow => false,
+        }
+    }
+
+    /// Returns a key for sorting mana symbols into the conventional order: variable costs,
+    /// other generic costs, colourless mana and snow mana come first, followed by all
+    /// coloured symbols in WUBRG order.
+    fn canonical_key(&self) -> (u8, u8, u8, u8) {
+        match self {
+            Mana::Generic(GenericCost::Variable(_)) => (0, 0, 0, 0),
+            Mana::Generic(_) => (1, 0, 0, 0),
+            Mana::PhyrexianGeneric => (1, 0, 0, 1),
+            Mana::Colourless => (2, 0, 0, 0),
+            Mana::Snow => (3, 0, 0, 0),
+            Mana::Coloured(c) => (4, c.wubrg_position(), 0, 0),
+            Mana::Phyrexian(c) => (4, c.wubrg_position(), 0, 1),
+            Mana::Half(c) => (4, c.wubrg_position(), 0, 2),
+            Mana::MonoHybrid(c) => (4, c.wubrg_position(), 0, 3),
+            Mana::ColourHybrid(c) => (4, c.wubrg_position(), 0, 4),
+            Mana::DualHybrid(a, b) => (4, a.wubrg_position(), b.wubrg_position() + 1, 0),
+            Mana::DualHybridPhyrexian(a, b) => {
+                (4, a.wubrg_position(), b.wubrg_position() + 1, 1)
+            },
+        }
+    }
+
     /// Tries to convert a string without specifiers into coloured mana.
     ///
     /// # Parameters
     ///
     /// * `value` - the string to convert
     fn into_coloured(value: &str) -> Option<Mana> {
-        Colour::try_from(value).ok().map(|col| Mana::Coloured(col))
+        Colour::try_from(value).ok().map(Mana::Coloured)
     }
 
     /// Tries to convert a string without specifiers into colourless mana.
@@ -399,7 +572,7 @@ impl Mana {
     ///
     /// * `value` - the string to convert
     fn into_generic(value: &str) -> Option<Mana> {
-        GenericCost::try_from(value).ok().map(|gen| Mana::Generic(gen))
+        GenericCost::try_from(value).ok().map(Mana::Generic)
     }
 
     /// Tries to convert a string without specifiers into mono hybrid mana.
@@ -409,7 +582,17 @@ impl Mana {
     /// * `value` - the string to convert
     fn into_mono_hybrid(value: &str) -> Option<Mana> {
         value.strip_prefix(MANA_MONO_HYBRID)
-            .and_then(|stripped| Colour::try_from(stripped).ok().map(|col| Mana::MonoHybrid(col)))
+            .and_then(|stripped| Colour::try_from(stripped).ok().map(Mana::MonoHybrid))
+    }
+
+    /// Tries to convert a string without specifiers into colourless-or-colour hybrid mana.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - the string to convert
+    fn into_colour_hybrid(value: &str) -> Option<Mana> {
+        value.strip_prefix(MANA_COLOUR_HYBRID)
+            .and_then(|stripped| Colour::try_from(stripped).ok().map(Mana::ColourHybrid))
     }
 
     /// Tries to convert a string without specifiers into dual hybrid mana.
@@ -419,7 +602,7 @@ impl Mana {
     /// * `value` - the string to convert
     fn into_dual_hybrid(value: &str) -> Option<Mana> {
         let mut colours: Vec<Result<Colour, String>> = value.splitn(2, MANA_DUAL_HYBRID)
-            .map(|split| Colour::try_from(split))
+            .map(Colour::try_from)
             .collect();
         if colours.len() == 2 {
             match (colours.remove(0), colours.remove(0)) {
@@ -437,7 +620,7 @@ impl Mana {
     ///
     /// * `value` - the string to convert
     fn into_dual_hybrid_phyrexian(value: &str) -> Option<Mana> {
-        value.strip_suffix(MANA_PHYREXIAN).and_then(Mana::into_dual_hybrid).and_then(|dual| {
+        Mana::strip_phyrexian(value).and_then(Mana::into_dual_hybrid).and_then(|dual| {
             if let Mana::DualHybrid(a, b) = dual {
                 Some(Mana::DualHybridPhyrexian(a, b))
             } else {
@@ -452,8 +635,36 @@ impl Mana {
     ///
     /// * `value` - the string to convert
     fn into_phyrexian(value: &str) -> Option<Mana> {
-        value.strip_suffix(MANA_PHYREXIAN)
-            .and_then(|stripped| Colour::try_from(stripped).ok().map(|col| Mana::Phyrexian(col)))
+        Mana::strip_phyrexian(value)
+            .and_then(|stripped| Colour::try_from(stripped).ok().map(Mana::Phyrexian))
+    }
+
+    /// Tries to convert a string without specifiers into generic phyrexian mana.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - the string to convert
+    fn into_phyrexian_generic(value: &str) -> Option<Mana> {
+        if value.eq_ignore_ascii_case(MANA_PHYREXIAN_GENERIC) {
+            Some(Mana::PhyrexianGeneric)
+        } else {
+            None
+        }
+    }
+
+    /// Strips the phyrexian suffix from a string without specifiers ignoring case.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - the string to strip
+    fn strip_phyrexian(value: &str) -> Option<&str> {
+        let split = value.len().checked_sub(MANA_PHYREXIAN.len())?;
+        let (stripped, suffix) = (value.get(..split)?, value.get(split..)?);
+        if suffix.eq_ignore_ascii_case(MANA_PHYREXIAN) {
+            Some(stripped)
+        } else {
+            None
+        }
     }
 
     /// Tries to convert a string without specifiers into half mana.
@@ -463,7 +674,7 @@ impl Mana {
     /// * `value` - the string to convert
     fn into_half(value: &str) -> Option<Mana> {
         value.strip_prefix(MANA_HALF)
-            .and_then(|stripped| Colour::try_from(stripped).ok().map(|col| Mana::Half(col)))
+            .and_then(|stripped| Colour::try_from(stripped).ok().map(Mana::Half))
     }
 
     /// Tries to convert a string without specifiers into snow mana.
@@ -473,7 +684,7 @@ impl Mana {
     /// * `value` - the string to convert
     fn into_snow(value: &str) -> Option<Mana> {
         match value {
-            MANA_SNOW => Some(Mana::Colourless),
+            MANA_SNOW => Some(Mana::Snow),
             _ => None,
         }
     }
@@ -502,10 +713,12 @@ impl TryFrom<&str> for Mana {
                     .or(Mana::into_colourless(inner))
                     .or(Mana::into_generic(inner))
                     .or(Mana::into_mono_hybrid(inner))
+                    .or(Mana::into_colour_hybrid(inner))
                     .or(Mana::into_dual_hybrid(inner))
                     .or(Mana::into_dual_hybrid_phyrexian(inner))
                     .or(Mana::into_snow(inner))
                     .or(Mana::into_phyrexian(inner))
+                    .or(Mana::into_phyrexian_generic(inner))
                     .or(Mana::into_half(inner))
             })
             .ok_or(format!("{} is not valid mana.", value))
@@ -520,6 +733,14 @@ impl TryFrom<String> for Mana {
     }
 }
 
+impl FromStr for Mana {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Mana::try_from(value)
+    }
+}
+
 impl fmt::Display for Mana {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let inner: String = match self {
@@ -527,10 +748,12 @@ impl fmt::Display for Mana {
             Mana::Colourless => MANA_COLOURLESS.to_string(),
             Mana::Generic(amount) => amount.into(),
             Mana::MonoHybrid(colour) => format!("{}{}", MANA_MONO_HYBRID, colour),
+            Mana::ColourHybrid(colour) => format!("{}{}", MANA_COLOUR_HYBRID, colour),
             Mana::DualHybrid(colour_a,colour_b) => format!("{}{}{}", colour_a, MANA_DUAL_HYBRID, colour_b),
             Mana::DualHybridPhyrexian(colour_a,colour_b) => format!("{}{}{}{}", colour_a, MANA_DUAL_HYBRID, colour_b, MANA_PHYREXIAN),
             Mana::Snow => MANA_SNOW.to_string(),
             Mana::Phyrexian(colour) => format!("{}{}", colour, MANA_PHYREXIAN),
+            Mana::PhyrexianGeneric => MANA_PHYREXIAN_GENERIC.to_string(),
             Mana::Half(colour) => format!("{}{}", MANA_HALF, colour),
         };
         write!(f, "{}{}{}", MANA_SPECIFIER_START, inner, MANA_SPECIFIER_END)
@@ -538,7 +761,8 @@ impl fmt::Display for Mana {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
-/// The mana cost of a card.
+/// The mana cost of a card. Equality is structural, so the order of the symbols matters.
+/// Use [`equivalent`](ManaCost::equivalent) to compare mana costs regardless of their order.
 pub struct ManaCost {
     mana: Vec<Mana>,
 }
@@ -553,10 +777,246 @@ impl ManaCost {
         Self {mana}
     }
 
+    /// Returns an iterator over the mana symbols in the order they were parsed in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::{Colour, Mana, ManaCost};
+    /// use std::convert::TryFrom;
+    ///
+    /// let mana_cost = ManaCost::try_from("{U}{U}").unwrap();
+    /// assert!(mana_cost.symbols().all(|mana| *mana == Mana::Coloured(Colour::Blue)));
+    /// ```
+    pub fn symbols(&self) -> impl Iterator<Item = &Mana> {
+        self.mana.iter()
+    }
+
     /// Returns the converted mana cost.
     pub fn converted_mana_cost(&self) -> f64 {
         self.mana.iter().map(|m| m.converted_mana_cost()).sum()
     }
+
+    /// Returns the converted mana cost with the specified value substituted for every
+    /// variable cost like `{X}`, `{Y}` or `{Z}`.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - the value of each variable cost
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::ManaCost;
+    /// use std::convert::TryFrom;
+    ///
+    /// let mana_cost = ManaCost::try_from("{X}{X}{R}").unwrap();
+    /// assert_eq!(mana_cost.converted_mana_cost_with(3), 7.0);
+    /// assert_eq!(mana_cost.converted_mana_cost(), 1.0);
+    /// ```
+    pub fn converted_mana_cost_with(&self, x: u32) -> f64 {
+        self.mana.iter()
+            .map(|m| match m {
+                Mana::Generic(GenericCost::Variable(_)) => x as f64,
+                _ => m.converted_mana_cost(),
+            })
+            .sum()
+    }
+
+    /// Returns the mana value of this mana cost as an integer. Half mana is rounded up
+    /// and variable costs like `{X}` count as zero. An infinite cost saturates at
+    /// [`u32::MAX`](u32::MAX).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::ManaCost;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(ManaCost::try_from("{X}{X}{R}").unwrap().mana_value(), 1);
+    /// assert_eq!(ManaCost::try_from("{1}{HW}").unwrap().mana_value(), 2);
+    /// ```
+    pub fn mana_value(&self) -> u32 {
+        let cmc = self.converted_mana_cost();
+        if cmc.is_infinite() {
+            u32::MAX
+        } else {
+            cmc.ceil() as u32
+        }
+    }
+
+    /// Returns the number of mana symbols this mana cost consists of.
+    pub fn pip_count(&self) -> usize {
+        self.mana.len()
+    }
+
+    /// Returns all [`Colour`](Colour)s of the mana symbols in this mana cost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::{ColourSet, ManaCost};
+    /// use std::convert::TryFrom;
+    ///
+    /// let mana_cost = ManaCost::try_from("{2}{W}{B/P}").unwrap();
+    /// assert_eq!(mana_cost.colours(), ColourSet::orzhov());
+    /// ```
+    pub fn colours(&self) -> ColourSet {
+        Colour::all()
+            .into_iter()
+            .filter(|colour| self.mana.iter().any(|m| m.is_of_colour(*colour)))
+            .collect()
+    }
+
+    /// Returns the devotion of this mana cost to the specified [`Colour`](Colour), that is
+    /// the number of mana symbols of this colour. Every hybrid symbol counts once if any of
+    /// its colours matches.
+    ///
+    /// # Parameters
+    ///
+    /// * `colour` - the colour to count the devotion to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::{Colour, ManaCost};
+    /// use std::convert::TryFrom;
+    ///
+    /// let mana_cost = ManaCost::try_from("{2}{B}{B/R}{G/P}").unwrap();
+    /// assert_eq!(mana_cost.devotion(Colour::Black), 2);
+    /// assert_eq!(mana_cost.devotion(Colour::Green), 1);
+    /// ```
+    pub fn devotion(&self, colour: Colour) -> u32 {
+        self.mana.iter().filter(|m| m.is_of_colour(colour)).count() as u32
+    }
+
+    /// Checks if the converted mana cost of this and the other mana cost are equal.
+    /// Unlike comparing the converted mana costs directly this is robust against
+    /// rounding errors. Infinite costs are only equal to other infinite costs.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the mana cost to compare to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::ManaCost;
+    /// use std::convert::TryFrom;
+    ///
+    /// let half = ManaCost::try_from("{HW}{HR}").unwrap();
+    /// let one = ManaCost::try_from("{1}").unwrap();
+    /// assert!(half.cmc_eq(&one));
+    /// ```
+    pub fn cmc_eq(&self, other: &ManaCost) -> bool {
+        let (cmc, other_cmc) = (self.converted_mana_cost(), other.converted_mana_cost());
+        if cmc.is_infinite() || other_cmc.is_infinite() {
+            cmc == other_cmc
+        } else {
+            (cmc - other_cmc).abs() < CONVERTED_MANA_COST_EPSILON
+        }
+    }
+
+    /// Returns a copy of this mana cost with the symbols sorted into the conventional order:
+    /// variable and other generic costs first, then colourless and snow mana, then all
+    /// coloured symbols in WUBRG order. Symbols of the same kind like multiple generic costs
+    /// are ordered by their textual representation. The canonical form is also displayed when
+    /// formatting a mana cost with the alternate flag (`{:#}`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::ManaCost;
+    /// use std::convert::TryFrom;
+    ///
+    /// let mana_cost = ManaCost::try_from("{G}{W}{2}{X}").unwrap();
+    /// assert_eq!(mana_cost.canonicalize().to_string(), "{X}{2}{W}{G}");
+    /// assert_eq!(format!("{:#}", mana_cost), "{X}{2}{W}{G}");
+    /// ```
+    pub fn canonicalize(&self) -> ManaCost {
+        let mut mana = self.mana.clone();
+        mana.sort_by_cached_key(|m| (m.canonical_key(), String::from(m)));
+        ManaCost::new(mana)
+    }
+
+    /// Checks if this and the other mana cost consist of the same symbols regardless of
+    /// their order. In contrast `==` compares the symbols in the order they were parsed in.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the mana cost to compare to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::ManaCost;
+    /// use std::convert::TryFrom;
+    ///
+    /// let a = ManaCost::try_from("{U}{1}").unwrap();
+    /// let b = ManaCost::try_from("{1}{U}").unwrap();
+    /// assert_ne!(a, b);
+    /// assert!(a.equivalent(&b));
+    /// ```
+    pub fn equivalent(&self, other: &ManaCost) -> bool {
+        self.canonicalize() == other.canonicalize()
+    }
+
+    /// Returns the textual representation of the mana cost. The symbols are kept in the
+    /// order they were parsed in, so parsing a valid mana cost string and calling this
+    /// method reproduces the original string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::ManaCost;
+    /// use std::convert::TryFrom;
+    ///
+    /// let source = "{U}{1}{B/G/P}";
+    /// let mana_cost = ManaCost::try_from(source).unwrap();
+    /// assert_eq!(source, mana_cost.source_string());
+    /// ```
+    pub fn source_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Parses a mana cost string without failing on invalid symbols. All valid symbols are
+    /// kept in order, while an error message is returned for every symbol that could not
+    /// be parsed.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - the mana cost string to parse
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::ManaCost;
+    /// use std::convert::TryFrom;
+    ///
+    /// let (mana_cost, errors) = ManaCost::parse_lenient("{U}{Q}{1}");
+    /// assert_eq!(mana_cost, ManaCost::try_from("{U}{1}").unwrap());
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    pub fn parse_lenient(value: &str) -> (ManaCost, Vec<String>) {
+        let mut manas = Vec::new();
+        let mut errors: Vec<String> = Vec::new();
+        for mana in split_mana_string(value) {
+            match std::convert::TryInto::<Mana>::try_into(mana) {
+                Ok(m) => manas.push(m),
+                Err(e) => errors.push(e),
+            }
+        }
+        (ManaCost::new(manas), errors)
+    }
+}
+
+impl<'a> IntoIterator for &'a ManaCost {
+    type Item = &'a Mana;
+    type IntoIter = std::slice::Iter<'a, Mana>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.mana.iter()
+    }
 }
 
 impl From<ManaCost> for String {
@@ -575,16 +1035,9 @@ impl TryFrom<&str> for ManaCost {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut manas = Vec::new();
-        let mut errors: Vec<String> = Vec::new();
-        for mana in split_mana_string(value) {
-            match std::convert::TryInto::<Mana>::try_into(mana) {
-                Ok(m) => manas.push(m),
-                Err(e) => errors.push(e),
-            }
-        }
+        let (mana_cost, errors) = ManaCost::parse_lenient(value);
         if errors.is_empty() {
-            Ok(ManaCost::new(manas))
+            Ok(mana_cost)
         } else {
             let error_string = errors.join("\n[Cause]: ");
             Err(format!("{} is not a valid mana cost.\n[Cause]: {}", value, error_string))
@@ -630,8 +1083,19 @@ impl TryFrom<String> for ManaCost {
     }
 }
 
+impl FromStr for ManaCost {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ManaCost::try_from(value)
+    }
+}
+
 impl fmt::Display for ManaCost {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return write!(f, "{}", self.canonicalize());
+        }
         let mut s = String::new();
         for cost in &self.mana {
             let intermediate: String = cost.into();
@@ -641,6 +1105,191 @@ impl fmt::Display for ManaCost {
     }
 }
 
+/// A wrapper around a [`ManaCost`](ManaCost), whose equality and hashing are based on its
+/// symbols regardless of their order. This allows to group cards by their mana cost, e.g.
+/// by using the wrapper as key of a `HashMap`.
+///
+/// # Examples
+///
+/// ```
+/// use phyrexian_library::magic::colour::{CanonicalManaCost, ManaCost};
+/// use std::convert::TryFrom;
+///
+/// let a = CanonicalManaCost::from(ManaCost::try_from("{1}{W}{U}").unwrap());
+/// let b = CanonicalManaCost::from(ManaCost::try_from("{U}{1}{W}").unwrap());
+/// assert_eq!(a, b);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CanonicalManaCost(ManaCost);
+
+impl CanonicalManaCost {
+    /// Returns the wrapped mana cost with its symbols in their original order.
+    pub fn inner(&self) -> &ManaCost {
+        &self.0
+    }
+
+    /// Returns the wrapped mana cost with its symbols in their original order.
+    pub fn into_inner(self) -> ManaCost {
+        self.0
+    }
+}
+
+impl From<ManaCost> for CanonicalManaCost {
+    fn from(mana_cost: ManaCost) -> Self {
+        CanonicalManaCost(mana_cost)
+    }
+}
+
+impl PartialEq for CanonicalManaCost {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.equivalent(&other.0)
+    }
+}
+
+impl Eq for CanonicalManaCost {}
+
+impl Hash for CanonicalManaCost {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.canonicalize().hash(state);
+    }
+}
+
+impl fmt::Display for CanonicalManaCost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#}", self.0)
+    }
+}
+
+/// A builder accumulating [`Mana`](Mana) symbols into a [`ManaCost`](ManaCost).
+/// All generic mana added is combined into a single symbol.
+///
+/// # Examples
+///
+/// ```
+/// use phyrexian_library::magic::colour::{Colour, ManaCostBuilder};
+///
+/// let mana_cost = ManaCostBuilder::default()
+///     .coloured(Colour::White, 2)
+///     .generic(2)
+///     .build();
+/// assert_eq!(mana_cost.to_string(), "{2}{W}{W}");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ManaCostBuilder {
+    generic: Option<u32>,
+    mana: Vec<Mana>,
+}
+
+impl ManaCostBuilder {
+    /// Adds the specified amount of generic mana. A generic cost of zero is only kept
+    /// if the mana cost would otherwise be empty, e.g. `{0}`.
+    ///
+    /// # Parameters
+    ///
+    /// * `amount` - the amount of generic mana
+    pub fn generic(&mut self, amount: u32) -> &mut Self {
+        self.generic = Some(self.generic.unwrap_or(0).saturating_add(amount));
+        self
+    }
+
+    /// Adds a variable generic cost like `{X}`.
+    ///
+    /// # Parameters
+    ///
+    /// * `variable` - the name of the variable
+    pub fn variable<T: Into<String>>(&mut self, variable: T) -> &mut Self {
+        self.symbol(Mana::Generic(GenericCost::Variable(variable.into())))
+    }
+
+    /// Adds the specified number of coloured mana symbols.
+    ///
+    /// # Parameters
+    ///
+    /// * `colour` - the colour of the mana
+    /// * `count` - the number of symbols
+    pub fn coloured(&mut self, colour: Colour, count: usize) -> &mut Self {
+        self.repeated(Mana::Coloured(colour), count)
+    }
+
+    /// Adds the specified number of colourless mana symbols.
+    ///
+    /// # Parameters
+    ///
+    /// * `count` - the number of symbols
+    pub fn colourless(&mut self, count: usize) -> &mut Self {
+        self.repeated(Mana::Colourless, count)
+    }
+
+    /// Adds the specified number of snow mana symbols.
+    ///
+    /// # Parameters
+    ///
+    /// * `count` - the number of symbols
+    pub fn snow(&mut self, count: usize) -> &mut Self {
+        self.repeated(Mana::Snow, count)
+    }
+
+    /// Adds a hybrid mana symbol of two colours.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - the first colour
+    /// * `b` - the second colour
+    pub fn hybrid(&mut self, a: Colour, b: Colour) -> &mut Self {
+        self.symbol(Mana::DualHybrid(a, b))
+    }
+
+    /// Adds a hybrid mana symbol of two generic mana or a colour.
+    ///
+    /// # Parameters
+    ///
+    /// * `colour` - the colour of the mana
+    pub fn mono_hybrid(&mut self, colour: Colour) -> &mut Self {
+        self.symbol(Mana::MonoHybrid(colour))
+    }
+
+    /// Adds a phyrexian mana symbol.
+    ///
+    /// # Parameters
+    ///
+    /// * `colour` - the colour of the mana
+    pub fn phyrexian(&mut self, colour: Colour) -> &mut Self {
+        self.symbol(Mana::Phyrexian(colour))
+    }
+
+    /// Adds an arbitrary mana symbol.
+    ///
+    /// # Parameters
+    ///
+    /// * `mana` - the mana symbol
+    pub fn symbol(&mut self, mana: Mana) -> &mut Self {
+        self.mana.push(mana);
+        self
+    }
+
+    /// Builds the [`ManaCost`](ManaCost) with its symbols in
+    /// [`canonical`](ManaCost::canonicalize) order.
+    pub fn build(&self) -> ManaCost {
+        let generic = self
+            .generic
+            .filter(|&amount| amount > 0 || self.mana.is_empty())
+            .map(|amount| i32::try_from(amount).unwrap_or(i32::MAX))
+            .map(|amount| Mana::Generic(GenericCost::Integer(amount)));
+        ManaCost::new(generic.into_iter().chain(self.mana.iter().cloned()).collect()).canonicalize()
+    }
+
+    /// Adds the specified number of copies of the mana symbol.
+    ///
+    /// # Parameters
+    ///
+    /// * `mana` - the mana symbol
+    /// * `count` - the number of symbols
+    fn repeated(&mut self, mana: Mana, count: usize) -> &mut Self {
+        self.mana.extend(std::iter::repeat_n(mana, count));
+        self
+    }
+}
+
 /// The `GenericCost` enum defines types of generic mana costs.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GenericCost {
@@ -661,13 +1310,40 @@ impl GenericCost {
         }
     }
 
+    /// Compares two generic costs like [`cmp`](Ord::cmp), but treats variable costs like
+    /// `{X}` as a large unknown. Variable costs are greater than all finite costs, but
+    /// less than an infinite cost.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the generic cost to compare to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::colour::GenericCost;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x = GenericCost::Variable("X".to_string());
+    /// assert_eq!(x.cmp_variable_high(&GenericCost::Integer(20)), Ordering::Greater);
+    /// assert_eq!(x.cmp_variable_high(&GenericCost::Infinity), Ordering::Less);
+    /// ```
+    pub fn cmp_variable_high(&self, other: &Self) -> Ordering {
+        let rank = |cost: &GenericCost| match cost {
+            GenericCost::Half | GenericCost::Integer(_) => 0,
+            GenericCost::Variable(_) => 1,
+            GenericCost::Infinity => 2,
+        };
+        rank(self).cmp(&rank(other)).then_with(|| self.cmp(other))
+    }
+
     /// Tries to convert the specified string to a a `GenericCost::Integer`.
     ///
     /// # Parameters
     ///
     /// * `i` - the potential integer
     fn to_integer(i: &str) -> Result<GenericCost, std::num::ParseIntError> {
-        i.parse::<i32>().map(|i| GenericCost::Integer(i))
+        i.parse::<i32>().map(GenericCost::Integer)
     }
 
     /// Tries to convert the specified string to a a `GenericCost::Variable`.
@@ -726,7 +1402,7 @@ impl PartialOrd for GenericCost {
 impl Ord for GenericCost {
     fn cmp(&self, other: &Self) -> Ordering {
         self.converted_mana_cost().partial_cmp(&other.converted_mana_cost())
-            .expect(&format!("Generic cost {} and {} must be fully comparable.", self, other))
+            .unwrap_or_else(|| panic!("Generic cost {} and {} must be fully comparable.", self, other))
     }
 }
 
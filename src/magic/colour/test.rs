@@ -23,3 +23,355 @@ fn test_split_mana_string() {
         );
     }
 }
+
+#[test]
+/// Tests if parsing and displaying a `ManaCost` reproduces the original string.
+fn test_mana_cost_round_trip() {
+    let costs = vec!(
+        "{2}{W}{U}",
+        "{U}{1}",
+        "{X}{X}{R}",
+        "{1}{G/U}{G/U}",
+        "{W/P}{W/P}",
+        "{2/B}{2/B}{2/B}",
+        "{B/G/P}",
+        "{3}{S}",
+        "{C}{C}",
+        "{HR}",
+        "{∞}",
+    );
+    for cost in costs {
+        let mana_cost = ManaCost::try_from(cost).unwrap();
+        assert_eq!(cost, mana_cost.to_string());
+        assert_eq!(cost, mana_cost.source_string());
+    }
+}
+
+#[test]
+/// Tests if snow mana is parsed as such and not as colourless mana.
+fn test_parse_snow() {
+    assert_eq!(Mana::try_from("{S}"), Ok(Mana::Snow));
+    assert_eq!(Mana::try_from("{C}"), Ok(Mana::Colourless));
+}
+
+#[test]
+/// Tests if the guild constructors of `ColourSet` create the correct sets.
+fn test_guild_constructors() {
+    let izzet = ColourSet::izzet();
+    assert_eq!(izzet.length(), 2);
+    assert!(izzet.has(Colour::Blue));
+    assert!(izzet.has(Colour::Red));
+    let guilds = vec!(
+        (ColourSet::azorius(), "Azorius Senate"),
+        (ColourSet::dimir(), "House Dimir"),
+        (ColourSet::rakdos(), "Cult of Rakdos"),
+        (ColourSet::gruul(), "Gruul Clans"),
+        (ColourSet::selesnya(), "Selesnya Conclave"),
+        (ColourSet::orzhov(), "Orzhov Syndicate"),
+        (ColourSet::izzet(), "Izzet League"),
+        (ColourSet::golgari(), "Golgari Swarm"),
+        (ColourSet::boros(), "Boros Legion"),
+        (ColourSet::simic(), "Simic Combine"),
+    );
+    for (guild, name) in guilds {
+        assert_eq!(guild.length(), 2);
+        assert_eq!(guild.to_string(), name);
+    }
+}
+
+#[test]
+/// Tests if the `cmc_eq` method of `ManaCost` works as expected.
+fn test_cmc_eq() {
+    let cost = |value: &str| ManaCost::try_from(value).unwrap();
+    assert!(cost("{HW}{HU}{HB}{HR}{HG}{HW}").cmc_eq(&cost("{3}")));
+    assert!(cost("{HW}{1}").cmc_eq(&cost("{HR}{C}")));
+    assert!(!cost("{HW}{1}").cmc_eq(&cost("{1}")));
+    assert!(!cost("{HW}").cmc_eq(&cost("{1}")));
+    assert!(cost("{∞}").cmc_eq(&cost("{∞}{1}")));
+    assert!(!cost("{∞}").cmc_eq(&cost("{1000}")));
+}
+
+#[test]
+/// Tests if the `devotion` method of `ManaCost` works as expected.
+fn test_devotion() {
+    let mana_cost = ManaCost::try_from("{2}{B}{B/R}{G/P}").unwrap();
+    assert_eq!(mana_cost.devotion(Colour::Black), 2);
+    assert_eq!(mana_cost.devotion(Colour::Green), 1);
+    assert_eq!(mana_cost.devotion(Colour::Red), 1);
+    assert_eq!(mana_cost.devotion(Colour::White), 0);
+    let mana_cost = ManaCost::try_from("{2/U}{HU}{C}{S}{X}{W/U/P}").unwrap();
+    assert_eq!(mana_cost.devotion(Colour::Blue), 3);
+    assert_eq!(mana_cost.devotion(Colour::White), 1);
+    assert_eq!(ManaCost::try_from("{10}").unwrap().devotion(Colour::Blue), 0);
+}
+
+#[test]
+/// Tests if the `mana_value` and `pip_count` methods of `ManaCost` work as expected.
+fn test_mana_value_pip_count() {
+    let cost = |value: &str| ManaCost::try_from(value).unwrap();
+    assert_eq!(cost("{HW}").mana_value(), 1);
+    assert_eq!(cost("{HW}").pip_count(), 1);
+    assert_eq!(cost("{HW}{HR}").mana_value(), 1);
+    assert_eq!(cost("{2}{HR}").mana_value(), 3);
+    assert_eq!(cost("{X}{X}{R}").mana_value(), 1);
+    assert_eq!(cost("{X}{X}{R}").pip_count(), 3);
+    assert_eq!(cost("{10}{U}").mana_value(), 11);
+    assert_eq!(cost("{10}{U}").pip_count(), 2);
+    assert_eq!(cost("{∞}").mana_value(), u32::MAX);
+}
+
+#[test]
+/// Tests if colourless-or-colour hybrid mana is parsed and displayed as expected.
+fn test_colour_hybrid() {
+    let symbols = vec!(
+        ("{C/W}", Colour::White),
+        ("{C/U}", Colour::Blue),
+        ("{C/B}", Colour::Black),
+        ("{C/R}", Colour::Red),
+        ("{C/G}", Colour::Green),
+    );
+    for (symbol, colour) in symbols {
+        let mana = Mana::try_from(symbol).unwrap();
+        assert_eq!(mana, Mana::ColourHybrid(colour));
+        assert_eq!(mana.to_string(), symbol);
+        assert_eq!(mana.converted_mana_cost(), 1.0);
+        assert!(mana.is_of_colour(colour));
+    }
+    let mana_cost = ManaCost::try_from("{2}{C/W}{C/W}").unwrap();
+    assert_eq!(mana_cost.to_string(), "{2}{C/W}{C/W}");
+    assert!(Mana::try_from("{C/C}").is_err());
+}
+
+#[test]
+/// Tests if the `canonicalize` and `equivalent` methods of `ManaCost` work as expected.
+fn test_canonicalize_equivalent() {
+    let cost = |value: &str| ManaCost::try_from(value).unwrap();
+    assert_eq!(cost("{G}{U}{W}{R}{B}").canonicalize().to_string(), "{W}{U}{B}{R}{G}");
+    assert_eq!(cost("{R/G}{S}{C}{X}{1}{W}").canonicalize().to_string(), "{X}{1}{C}{S}{W}{R/G}");
+    assert_eq!(format!("{:#}", cost("{U}{2}")), "{2}{U}");
+    assert_eq!(format!("{}", cost("{U}{2}")), "{U}{2}");
+    assert!(cost("{1}{G/U}{B}").equivalent(&cost("{B}{G/U}{1}")));
+    assert!(cost("{W/P}{W}").equivalent(&cost("{W}{W/P}")));
+    assert!(!cost("{W/U}").equivalent(&cost("{U/W}")));
+    assert!(!cost("{1}{U}").equivalent(&cost("{1}{B}")));
+    assert!(!cost("{1}{U}").equivalent(&cost("{1}{U}{U}")));
+    assert_ne!(cost("{1}{U}"), cost("{U}{1}"));
+}
+
+#[test]
+/// Tests if the `from_name` method of `ColourSet` works as expected.
+fn test_colour_set_from_name() {
+    assert_eq!(ColourSet::from_name("Izzet League"), Ok(ColourSet::izzet()));
+    assert_eq!(ColourSet::from_name("house dimir"), Ok(ColourSet::dimir()));
+    assert_eq!(
+        ColourSet::from_name("Sultai"),
+        Ok(vec!(Colour::Black, Colour::Green, Colour::Blue).into_iter().collect())
+    );
+    assert_eq!(
+        ColourSet::from_name("Growth"),
+        Ok(vec!(Colour::Black, Colour::Blue, Colour::Green, Colour::White).into_iter().collect())
+    );
+    assert_eq!(ColourSet::from_name("Colorless"), Ok(ColourSet::new()));
+    assert!(ColourSet::from_name("Izzet").is_err());
+    assert!(ColourSet::from_name("").is_err());
+    let colours = Colour::all();
+    for mask in 0..32 {
+        let set: ColourSet = colours.iter()
+            .enumerate()
+            .filter(|(index, _)| mask & (1 << index) != 0)
+            .map(|(_, colour)| *colour)
+            .collect();
+        assert_eq!(ColourSet::from_name(&set.to_string()), Ok(set));
+    }
+}
+
+#[test]
+/// Tests if the `converted_mana_cost_with` method of `ManaCost` works as expected.
+fn test_converted_mana_cost_with() {
+    let cost = |value: &str| ManaCost::try_from(value).unwrap();
+    assert_eq!(cost("{X}{X}{R}").converted_mana_cost_with(3), 7.0);
+    assert_eq!(cost("{X}{X}{R}").converted_mana_cost_with(0), 1.0);
+    assert_eq!(cost("{X}{Y}{Z}").converted_mana_cost_with(2), 6.0);
+    assert_eq!(cost("{2}{HW}").converted_mana_cost_with(5), 2.5);
+    assert!(cost("{∞}{X}").converted_mana_cost_with(1).is_infinite());
+}
+
+#[test]
+/// Tests if `Colour::all` returns all colours in WUBRG order.
+fn test_colour_all() {
+    assert_eq!(
+        Colour::all(),
+        [Colour::White, Colour::Blue, Colour::Black, Colour::Red, Colour::Green]
+    );
+    let colours: ColourSet = Colour::all().into_iter().collect();
+    assert_eq!(colours.length(), 5);
+    assert_eq!(colours.to_string(), "WUBRG");
+}
+
+#[test]
+/// Tests if generic phyrexian mana is parsed and displayed as expected.
+fn test_phyrexian_generic() {
+    let mana = Mana::try_from("{2/P}").unwrap();
+    assert_eq!(mana, Mana::PhyrexianGeneric);
+    assert_eq!(mana.to_string(), "{2/P}");
+    assert_eq!(mana.converted_mana_cost(), 2.0);
+    assert!(Colour::all().iter().all(|colour| !mana.is_of_colour(*colour)));
+    assert_eq!(Mana::try_from("{W/P}"), Ok(Mana::Phyrexian(Colour::White)));
+    assert_eq!(Mana::try_from("{2/W}"), Ok(Mana::MonoHybrid(Colour::White)));
+    let mana_cost = ManaCost::try_from("{1}{2/P}{U}").unwrap();
+    assert_eq!(mana_cost.to_string(), "{1}{2/P}{U}");
+    assert_eq!(mana_cost.converted_mana_cost(), 4.0);
+    assert!(Mana::try_from("{3/P}").is_err());
+}
+
+#[test]
+/// Tests if iterating the symbols of a `ManaCost` works as expected.
+fn test_mana_cost_symbols() {
+    let mana_cost = ManaCost::try_from("{X}{2}{G/U}{B/P}{S}").unwrap();
+    let expected = vec!(
+        Mana::Generic(GenericCost::Variable("X".to_string())),
+        Mana::Generic(GenericCost::Integer(2)),
+        Mana::DualHybrid(Colour::Green, Colour::Blue),
+        Mana::Phyrexian(Colour::Black),
+        Mana::Snow,
+    );
+    assert_eq!(mana_cost.symbols().cloned().collect::<Vec<Mana>>(), expected);
+    let mut iterated = Vec::new();
+    for symbol in &mana_cost {
+        iterated.push(symbol.clone());
+    }
+    assert_eq!(iterated, expected);
+}
+
+#[test]
+/// Tests if lenient parsing of a `ManaCost` keeps the valid symbols and reports the invalid ones.
+fn test_mana_cost_parse_lenient() {
+    let (mana_cost, errors) = ManaCost::parse_lenient("{2}{W}{Q}{U/P}");
+    assert_eq!(mana_cost.mana, vec!(
+        Mana::Generic(GenericCost::Integer(2)),
+        Mana::Coloured(Colour::White),
+        Mana::Phyrexian(Colour::Blue),
+    ));
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("{Q}"));
+    let (mana_cost, errors) = ManaCost::parse_lenient("{G}{G}");
+    assert_eq!(mana_cost, ManaCost::try_from("{G}{G}").unwrap());
+    assert!(errors.is_empty());
+}
+
+#[test]
+/// Tests if comparing `GenericCost`s with variable costs as high unknowns works as expected.
+fn test_generic_cost_cmp_variable_high() {
+    let mut costs: Vec<GenericCost> = vec!("3", "X", "∞", "1", "½")
+        .into_iter()
+        .map(|cost| GenericCost::try_from(cost).unwrap())
+        .collect();
+    costs.sort_by(GenericCost::cmp_variable_high);
+    assert_eq!(costs, vec!(
+        GenericCost::Half,
+        GenericCost::Integer(1),
+        GenericCost::Integer(3),
+        GenericCost::Variable("X".to_string()),
+        GenericCost::Infinity,
+    ));
+    // The default ordering is unchanged.
+    costs.sort();
+    assert_eq!(costs[0], GenericCost::Variable("X".to_string()));
+}
+
+#[test]
+/// Tests if parsing a `Colour` ignores case.
+fn test_colour_conversion_ignoring_case() {
+    assert_eq!(Colour::try_from("W"), Ok(Colour::White));
+    assert_eq!(Colour::try_from("w"), Ok(Colour::White));
+    assert_eq!(Colour::try_from("g".to_string()), Ok(Colour::Green));
+    assert_eq!(Colour::White.to_string(), "W");
+    assert!(Colour::try_from("q").is_err());
+    assert_eq!(Mana::try_from("{u}"), Ok(Mana::Coloured(Colour::Blue)));
+    assert_eq!(Mana::try_from("{w/u}"), Ok(Mana::DualHybrid(Colour::White, Colour::Blue)));
+    assert_eq!(Mana::try_from("{2/r}"), Ok(Mana::MonoHybrid(Colour::Red)));
+    assert_eq!(Mana::try_from("{b/P}"), Ok(Mana::Phyrexian(Colour::Black)));
+    assert_eq!(Mana::try_from("{w/p}"), Ok(Mana::Phyrexian(Colour::White)));
+    assert_eq!(Mana::try_from("{2/p}"), Ok(Mana::PhyrexianGeneric));
+    assert_eq!(
+        Mana::try_from("{g/u/p}"),
+        Ok(Mana::DualHybridPhyrexian(Colour::Green, Colour::Blue))
+    );
+    assert_eq!(Mana::try_from("{w/u}").unwrap().to_string(), "{W/U}");
+}
+
+#[test]
+/// Tests if the half and infinite generic mana symbols are parsed and round-trip correctly.
+fn test_half_and_infinite_generic_mana() {
+    let half = Mana::try_from("{½}").unwrap();
+    assert_eq!(half, Mana::Generic(GenericCost::Half));
+    assert_eq!(half.to_string(), "{½}");
+    assert_eq!(half.converted_mana_cost(), 0.5);
+    let infinity = Mana::try_from("{∞}").unwrap();
+    assert_eq!(infinity, Mana::Generic(GenericCost::Infinity));
+    assert_eq!(infinity.to_string(), "{∞}");
+    assert!(infinity.converted_mana_cost().is_infinite());
+    let mana_cost = ManaCost::try_from("{∞}{½}{R}").unwrap();
+    assert_eq!(mana_cost.to_string(), "{∞}{½}{R}");
+}
+
+#[test]
+/// Tests if building a `ManaCost` with the `ManaCostBuilder` works as expected.
+fn test_mana_cost_builder() {
+    let mana_cost = ManaCostBuilder::default()
+        .coloured(Colour::White, 2)
+        .generic(1)
+        .generic(1)
+        .build();
+    assert_eq!(mana_cost.to_string(), "{2}{W}{W}");
+    assert_eq!(mana_cost.converted_mana_cost(), 4.0);
+    assert_eq!(mana_cost, ManaCost::try_from("{2}{W}{W}").unwrap());
+    let mana_cost = ManaCostBuilder::default()
+        .phyrexian(Colour::Green)
+        .hybrid(Colour::Blue, Colour::Red)
+        .variable("X")
+        .snow(1)
+        .colourless(1)
+        .mono_hybrid(Colour::Black)
+        .build();
+    assert!(mana_cost.equivalent(&ManaCost::try_from("{X}{C}{S}{2/B}{U/R}{G/P}").unwrap()));
+    assert_eq!(mana_cost, mana_cost.canonicalize());
+    assert_eq!(ManaCostBuilder::default().build(), ManaCost::new(Vec::new()));
+    let mana_cost = ManaCostBuilder::default().generic(0).coloured(Colour::Red, 1).build();
+    assert_eq!(mana_cost.to_string(), "{R}");
+    assert_eq!(ManaCostBuilder::default().generic(0).build().to_string(), "{0}");
+    let mana_cost = ManaCostBuilder::default().generic(u32::MAX).generic(1).build();
+    assert_eq!(mana_cost.to_string(), format!("{{{}}}", i32::MAX));
+}
+
+#[test]
+/// Tests if `Colour`, `Mana` and `ManaCost` can be parsed from a string.
+fn test_parse() {
+    assert_eq!("U".parse::<Colour>(), Ok(Colour::Blue));
+    assert!("Q".parse::<Colour>().is_err());
+    let mana: Mana = "{G/P}".parse().unwrap();
+    assert_eq!(mana, Mana::try_from("{G/P}").unwrap());
+    assert_eq!(mana.to_string().parse::<Mana>(), Ok(mana));
+    assert!("{Q}".parse::<Mana>().is_err());
+    let mana_cost: ManaCost = "{2}{W}{U}".parse().unwrap();
+    assert_eq!(mana_cost, ManaCost::try_from("{2}{W}{U}").unwrap());
+    assert_eq!(mana_cost.to_string().parse::<ManaCost>(), Ok(mana_cost));
+    assert!("{2}{Q}".parse::<ManaCost>().is_err());
+}
+
+#[test]
+/// Tests if `CanonicalManaCost` ignores the order of the symbols.
+fn test_canonical_mana_cost() {
+    let canonical = |cost: &str| CanonicalManaCost::from(ManaCost::try_from(cost).unwrap());
+    let mut costs = HashSet::new();
+    costs.insert(canonical("{1}{W}{U}"));
+    costs.insert(canonical("{1}{U}{W}"));
+    assert_eq!(costs.len(), 1);
+    costs.insert(canonical("{2}{W}{U}"));
+    assert_eq!(costs.len(), 2);
+    assert_eq!(canonical("{X}{2}{1}"), canonical("{1}{X}{2}"));
+    assert_ne!(canonical("{W}{W}"), canonical("{W}"));
+    assert_eq!(canonical("{U}{1}{W}").to_string(), "{1}{W}{U}");
+    assert_eq!(canonical("{U}{1}{W}").inner().to_string(), "{U}{1}{W}");
+    assert_eq!(canonical("{U}{W}").into_inner(), ManaCost::try_from("{U}{W}").unwrap());
+}
@@ -0,0 +1,294 @@
+//! The 'deck' module provides structures for deck building.
+
+use super::card::{Card, CardSet};
+use super::colour::ColourSet;
+use super::format::Format;
+use super::language::LocalisedString;
+use super::legality::Legality;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Getters, Serialize, Deserialize)]
+/// A deck of [`Card`](Card)s referenced by their [`UUID`](uuid::Uuid)s.
+pub struct Deck {
+    #[getset(get = "pub")]
+    /// The name of the deck.
+    name: LocalisedString,
+
+    /// The number of copies per card in the main deck.
+    main: HashMap<Uuid, u32>,
+
+    /// The number of copies per card in the sideboard.
+    sideboard: HashMap<Uuid, u32>,
+}
+
+impl Deck {
+    /// Creates a new empty `Deck`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - the name of the deck
+    pub fn new<T: Into<LocalisedString>>(name: T) -> Self {
+        Deck {
+            name: name.into(),
+            main: HashMap::new(),
+            sideboard: HashMap::new(),
+        }
+    }
+
+    /// Adds the specified number of copies of a card to the main deck.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the card
+    /// * `count` - the number of copies to add
+    pub fn add(&mut self, uuid: Uuid, count: u32) {
+        if count == 0 {
+            return;
+        }
+        let copies = self.main.entry(uuid).or_insert(0);
+        *copies = copies.saturating_add(count);
+    }
+
+    /// Removes the specified number of copies of a card from the main deck. If fewer copies
+    /// are present, the card is removed from the main deck completely.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the card
+    /// * `count` - the number of copies to remove
+    pub fn remove(&mut self, uuid: Uuid, count: u32) {
+        remove_copies(&mut self.main, uuid, count);
+    }
+
+    /// Adds the specified number of copies of a card to the sideboard.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the card
+    /// * `count` - the number of copies to add
+    pub fn add_to_sideboard(&mut self, uuid: Uuid, count: u32) {
+        if count == 0 {
+            return;
+        }
+        let copies = self.sideboard.entry(uuid).or_insert(0);
+        *copies = copies.saturating_add(count);
+    }
+
+    /// Removes the specified number of copies of a card from the sideboard. If fewer copies
+    /// are present, the card is removed from the sideboard completely.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the card
+    /// * `count` - the number of copies to remove
+    pub fn remove_from_sideboard(&mut self, uuid: Uuid, count: u32) {
+        remove_copies(&mut self.sideboard, uuid, count);
+    }
+
+    /// Returns the number of copies of a card in the main deck.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the card
+    pub fn main_count(&self, uuid: Uuid) -> u32 {
+        self.main.get(&uuid).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of copies of a card in the sideboard.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the card
+    pub fn sideboard_count(&self, uuid: Uuid) -> u32 {
+        self.sideboard.get(&uuid).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of cards in the main deck.
+    pub fn main_size(&self) -> u32 {
+        self.main.values().copied().fold(0, u32::saturating_add)
+    }
+
+    /// Returns the total number of cards in the sideboard.
+    pub fn sideboard_size(&self) -> u32 {
+        self.sideboard.values().copied().fold(0, u32::saturating_add)
+    }
+
+    /// Returns the [`UUID`](uuid::Uuid)s of all main deck cards whose colour identity is not
+    /// within the colour identity of the specified commander. Cards that are not part of the
+    /// specified [`CardSet`](CardSet) are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `commander` - the commander of the deck
+    /// * `set` - the set to look up the main deck cards in
+    pub fn commander_identity_violations(&self, commander: &Card, set: &CardSet) -> Vec<Uuid> {
+        let mut violations: Vec<Uuid> = self
+            .main
+            .keys()
+            .filter_map(|uuid| set.get(*uuid))
+            .filter(|card| !commander.colour_identity().is_subset(card.colour_identity()))
+            .map(|card| card.uuid())
+            .collect();
+        violations.sort();
+        violations
+    }
+
+    /// Validates the deck against the deck construction rules of the specified
+    /// [`Format`](Format) and returns all violations found. The deck is valid if the returned
+    /// list is empty. Copies in the sideboard count towards the copy limit, which does not
+    /// apply to basic lands. [`Restricted`](Legality::Restricted) cards are limited to a
+    /// single copy.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - the format to validate the deck for
+    /// * `lookup` - a function returning the card for a UUID
+    pub fn validate(
+        &self,
+        format: Format,
+        lookup: impl Fn(Uuid) -> Option<Card>,
+    ) -> Vec<DeckViolation> {
+        let mut violations = Vec::new();
+        let main_size = self.main_size();
+        if main_size < format.minimum_deck_size() {
+            violations.push(DeckViolation::TooFewCards {
+                minimum: format.minimum_deck_size(),
+                actual: main_size,
+            });
+        }
+        let uuids: BTreeSet<Uuid> =
+            self.main.keys().chain(self.sideboard.keys()).copied().collect();
+        for uuid in uuids {
+            let card = match lookup(uuid) {
+                Some(card) => card,
+                None => {
+                    violations.push(DeckViolation::UnknownCard(uuid));
+                    continue;
+                }
+            };
+            let count = self.main_count(uuid).saturating_add(self.sideboard_count(uuid));
+            let legality = card.legality_in(format);
+            let limit = match legality {
+                Legality::Legal => format.copy_limit(),
+                Legality::Restricted => 1,
+                Legality::Banned | Legality::NotLegal => {
+                    violations.push(DeckViolation::NotLegal {uuid, legality});
+                    continue;
+                }
+            };
+            if count > limit && !card.is_basic_land() {
+                violations.push(DeckViolation::TooManyCopies {uuid, limit, count});
+            }
+        }
+        violations
+    }
+
+    /// Returns the colour identity of the deck, which is the union of the colour identities
+    /// of all cards in the main deck. Cards that cannot be looked up are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `lookup` - a function returning the card for a UUID
+    pub fn colour_identity(&self, lookup: impl Fn(Uuid) -> Option<Card>) -> ColourSet {
+        self.main
+            .keys()
+            .filter_map(|uuid| lookup(*uuid))
+            .flat_map(|card| card.colour_identity().clone())
+            .collect()
+    }
+
+    /// Returns the mana curve of the deck, which maps each
+    /// [`mana value`](super::colour::ManaCost::mana_value) to the number of non-land cards
+    /// in the main deck with that mana value. Cards without a mana cost have a mana value
+    /// of zero. Cards that cannot be looked up are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `lookup` - a function returning the card for a UUID
+    pub fn mana_curve(&self, lookup: impl Fn(Uuid) -> Option<Card>) -> BTreeMap<u32, u32> {
+        let mut curve = BTreeMap::new();
+        for (uuid, count) in &self.main {
+            if let Some(card) = lookup(*uuid).filter(|card| !card.is_land()) {
+                let mana_value = card.mana_cost().as_ref().map_or(0, |cost| cost.mana_value());
+                let cards = curve.entry(mana_value).or_insert(0u32);
+                *cards = cards.saturating_add(*count);
+            }
+        }
+        curve
+    }
+}
+
+/// A 'DeckViolation' describes why a [`Deck`](Deck) is not valid in a [`Format`](Format).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeckViolation {
+    /// The main deck contains fewer cards than required.
+    TooFewCards {
+        /// The minimum number of cards in the main deck.
+        minimum: u32,
+        /// The actual number of cards in the main deck.
+        actual: u32,
+    },
+    /// The deck contains more copies of a card than allowed.
+    TooManyCopies {
+        /// The UUID of the card.
+        uuid: Uuid,
+        /// The maximum number of allowed copies.
+        limit: u32,
+        /// The actual number of copies in the main deck and sideboard.
+        count: u32,
+    },
+    /// The deck contains a card that is banned or not legal in the format.
+    NotLegal {
+        /// The UUID of the card.
+        uuid: Uuid,
+        /// The legality of the card in the format.
+        legality: Legality,
+    },
+    /// The deck contains a card that could not be looked up.
+    UnknownCard(Uuid),
+}
+
+impl fmt::Display for DeckViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckViolation::TooFewCards {minimum, actual} => write!(
+                f,
+                "The deck contains {} cards, but at least {} are required.",
+                actual, minimum
+            ),
+            DeckViolation::TooManyCopies {uuid, limit, count} => write!(
+                f,
+                "The deck contains {} copies of card {}, but at most {} are allowed.",
+                count, uuid, limit
+            ),
+            DeckViolation::NotLegal {uuid, legality} => {
+                write!(f, "The card {} is {}.", uuid, legality.to_string().to_lowercase())
+            }
+            DeckViolation::UnknownCard(uuid) => write!(f, "The card {} is unknown.", uuid),
+        }
+    }
+}
+
+/// Removes the specified number of copies of a card from a deck section. Cards without
+/// any remaining copies are removed from the section.
+///
+/// # Parameters
+///
+/// * `section` - the main deck or sideboard
+/// * `uuid` - the UUID of the card
+/// * `count` - the number of copies to remove
+fn remove_copies(section: &mut HashMap<Uuid, u32>, uuid: Uuid, count: u32) {
+    if let Some(copies) = section.get_mut(&uuid) {
+        if *copies > count {
+            *copies -= count;
+        } else {
+            section.remove(&uuid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,240 @@
+use super::*;
+use crate::magic::card::{CardBuilder, CardSetBuilder};
+use crate::magic::colour::{Colour, ColourSet, ManaCost};
+use crate::magic::legality::Legality;
+use crate::magic::rarity::Rarity;
+use chrono::NaiveDate;
+use std::convert::TryFrom;
+
+/// Returns a builder of a colourless creature with all required fields set.
+fn card_builder(uuid: u128) -> CardBuilder {
+    let mut builder = CardBuilder::default();
+    builder
+        .border_colour("black".to_string())
+        .card_type(LocalisedString::new("Creature"))
+        .colour(ColourSet::new())
+        .colour_identity(ColourSet::new())
+        .name(LocalisedString::new(format!("Card {}", uuid)))
+        .number(uuid.to_string())
+        .rarity(Rarity::Common)
+        .set_code("TEST".to_string())
+        .uuid(Uuid::from_u128(uuid));
+    builder
+}
+
+fn card_set(cards: Vec<Card>) -> CardSet {
+    let mut set = CardSetBuilder::default()
+        .code("TEST".to_string())
+        .keyrune("test".to_string())
+        .name(LocalisedString::new("Test"))
+        .release_date(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap())
+        .build()
+        .unwrap();
+    for card in cards {
+        set.insert(card);
+    }
+    set
+}
+
+#[test]
+/// Tests if the `commander_identity_violations` method of `Deck` works as expected.
+fn test_commander_identity_violations() {
+    let card = |uuid: u128, colour_identity: ColourSet| {
+        card_builder(uuid)
+            .colour(colour_identity.clone())
+            .colour_identity(colour_identity)
+            .build()
+            .unwrap()
+    };
+    let simic: ColourSet = vec!(Colour::Green, Colour::Blue).into_iter().collect();
+    let commander = card(1, simic);
+    let green = card(2, vec!(Colour::Green).into_iter().collect());
+    let colourless = card(3, ColourSet::new());
+    let black = card(4, vec!(Colour::Black).into_iter().collect());
+    let mut deck = Deck::new("Simic");
+    deck.add(green.uuid(), 4);
+    deck.add(colourless.uuid(), 1);
+    deck.add(black.uuid(), 1);
+    // Cards missing from the set are ignored.
+    deck.add(Uuid::from_u128(5), 1);
+    let set = card_set(vec!(commander.clone(), green, colourless, black.clone()));
+    assert_eq!(deck.commander_identity_violations(&commander, &set), vec!(black.uuid()));
+}
+
+#[test]
+/// Tests if adding cards to a `Deck` works as expected.
+fn test_add() {
+    let mut deck = Deck::new("Test");
+    deck.add(Uuid::from_u128(1), 2);
+    deck.add(Uuid::from_u128(1), 1);
+    deck.add_to_sideboard(Uuid::from_u128(1), 1);
+    assert_eq!(deck.main_count(Uuid::from_u128(1)), 3);
+    assert_eq!(deck.sideboard_count(Uuid::from_u128(1)), 1);
+    assert_eq!(deck.main_count(Uuid::from_u128(2)), 0);
+    // Adding no copies does not create an entry and adding too many copies saturates.
+    deck.add(Uuid::from_u128(2), 0);
+    deck.add_to_sideboard(Uuid::from_u128(2), 0);
+    assert!(!deck.main.contains_key(&Uuid::from_u128(2)));
+    assert!(!deck.sideboard.contains_key(&Uuid::from_u128(2)));
+    deck.add(Uuid::from_u128(1), u32::MAX);
+    deck.add_to_sideboard(Uuid::from_u128(1), u32::MAX);
+    assert_eq!(deck.main_count(Uuid::from_u128(1)), u32::MAX);
+    assert_eq!(deck.sideboard_count(Uuid::from_u128(1)), u32::MAX);
+    deck.add(Uuid::from_u128(3), u32::MAX / 2 + 1);
+    assert_eq!(deck.main_size(), u32::MAX);
+    assert_eq!(deck.sideboard_size(), u32::MAX);
+}
+
+#[test]
+/// Tests if removing cards from a `Deck` works as expected and clamps at zero.
+fn test_remove() {
+    let mut deck = Deck::new("Test");
+    deck.add(Uuid::from_u128(1), 4);
+    deck.add_to_sideboard(Uuid::from_u128(2), 2);
+    deck.remove(Uuid::from_u128(1), 1);
+    assert_eq!(deck.main_count(Uuid::from_u128(1)), 3);
+    deck.remove(Uuid::from_u128(1), 5);
+    assert_eq!(deck.main_count(Uuid::from_u128(1)), 0);
+    assert!(deck.main.is_empty());
+    deck.remove_from_sideboard(Uuid::from_u128(2), 3);
+    assert!(deck.sideboard.is_empty());
+    // Removing cards that are not part of the deck does nothing.
+    deck.remove(Uuid::from_u128(3), 1);
+    assert!(deck.main.is_empty());
+}
+
+#[test]
+/// Tests if the `main_size` and `sideboard_size` methods of `Deck` work as expected.
+fn test_sizes() {
+    let mut deck = Deck::new("Test");
+    assert_eq!(deck.main_size(), 0);
+    assert_eq!(deck.sideboard_size(), 0);
+    deck.add(Uuid::from_u128(1), 4);
+    deck.add(Uuid::from_u128(2), 20);
+    deck.add_to_sideboard(Uuid::from_u128(3), 15);
+    assert_eq!(deck.main_size(), 24);
+    assert_eq!(deck.sideboard_size(), 15);
+}
+
+fn modern_set() -> CardSet {
+    let modern_card = |uuid: u128, card_type: &str, legality: Legality| {
+        let mut legalities = BTreeMap::new();
+        legalities.insert(Format::Modern.to_string(), legality);
+        card_builder(uuid)
+            .card_type(LocalisedString::new(card_type))
+            .legality(legalities)
+            .build()
+            .unwrap()
+    };
+    card_set(vec!(
+        modern_card(1, "Basic Land — Island", Legality::Legal),
+        modern_card(2, "Creature — Merfolk", Legality::Legal),
+        modern_card(3, "Artifact", Legality::Banned),
+        modern_card(4, "Instant", Legality::Restricted),
+    ))
+}
+
+#[test]
+/// Tests if a valid `Deck` passes validation.
+fn test_validate_valid() {
+    let set = modern_set();
+    let mut deck = Deck::new("Test");
+    deck.add(Uuid::from_u128(1), 56);
+    deck.add(Uuid::from_u128(2), 4);
+    assert!(deck.validate(Format::Modern, |uuid| set.get(uuid).cloned()).is_empty());
+}
+
+#[test]
+/// Tests if `Deck` validation reports too few cards and too many copies.
+fn test_validate_over_limit() {
+    let set = modern_set();
+    let mut deck = Deck::new("Test");
+    deck.add(Uuid::from_u128(1), 40);
+    deck.add(Uuid::from_u128(2), 4);
+    deck.add_to_sideboard(Uuid::from_u128(2), 1);
+    deck.add(Uuid::from_u128(4), 2);
+    let violations = deck.validate(Format::Modern, |uuid| set.get(uuid).cloned());
+    assert_eq!(violations, vec!(
+        DeckViolation::TooFewCards {minimum: 60, actual: 46},
+        DeckViolation::TooManyCopies {uuid: Uuid::from_u128(2), limit: 4, count: 5},
+        DeckViolation::TooManyCopies {uuid: Uuid::from_u128(4), limit: 1, count: 2},
+    ));
+}
+
+#[test]
+/// Tests if `Deck` validation saturates the number of copies instead of overflowing.
+fn test_validate_saturated() {
+    let set = modern_set();
+    let mut deck = Deck::new("Test");
+    deck.add(Uuid::from_u128(2), u32::MAX);
+    deck.add_to_sideboard(Uuid::from_u128(2), u32::MAX);
+    deck.add(Uuid::from_u128(1), 60);
+    let violations = deck.validate(Format::Modern, |uuid| set.get(uuid).cloned());
+    assert_eq!(violations, vec!(
+        DeckViolation::TooManyCopies {uuid: Uuid::from_u128(2), limit: 4, count: u32::MAX},
+    ));
+}
+
+#[test]
+/// Tests if `Deck` validation reports banned and unknown cards.
+fn test_validate_banned() {
+    let set = modern_set();
+    let mut deck = Deck::new("Test");
+    deck.add(Uuid::from_u128(1), 59);
+    deck.add_to_sideboard(Uuid::from_u128(3), 1);
+    deck.add(Uuid::from_u128(5), 1);
+    let violations = deck.validate(Format::Modern, |uuid| set.get(uuid).cloned());
+    assert_eq!(violations, vec!(
+        DeckViolation::NotLegal {uuid: Uuid::from_u128(3), legality: Legality::Banned},
+        DeckViolation::UnknownCard(Uuid::from_u128(5)),
+    ));
+    // Cards without a known legality are not legal.
+    let violations = deck.validate(Format::Pauper, |uuid| set.get(uuid).cloned());
+    assert!(violations.contains(
+        &DeckViolation::NotLegal {uuid: Uuid::from_u128(1), legality: Legality::NotLegal}
+    ));
+}
+
+#[test]
+/// Tests if the `colour_identity` and `mana_curve` methods of `Deck` work as expected.
+fn test_colour_identity_and_mana_curve() {
+    let costed_card = |uuid: u128, card_type: &str, mana_cost: Option<&str>| {
+        let mana_cost = mana_cost.map(|cost| ManaCost::try_from(cost).unwrap());
+        let colours = mana_cost.as_ref().map(ManaCost::colours).unwrap_or_default();
+        let mut builder = card_builder(uuid);
+        builder
+            .card_type(LocalisedString::new(card_type))
+            .colour(colours.clone())
+            .colour_identity(colours);
+        if let Some(mana_cost) = mana_cost {
+            builder.mana_cost(mana_cost);
+        }
+        builder.build().unwrap()
+    };
+    let set = card_set(vec!(
+        costed_card(1, "Basic Land — Forest", None),
+        costed_card(2, "Creature — Elf", Some("{G}")),
+        costed_card(3, "Instant", Some("{1}{U}")),
+        costed_card(4, "Sorcery", Some("{X}{G}{U}")),
+        costed_card(5, "Artifact", Some("{0}")),
+        costed_card(6, "Creature — Demon", Some("{3}{B}{B}")),
+    ));
+    let mut deck = Deck::new("Test");
+    deck.add(Uuid::from_u128(1), 20);
+    deck.add(Uuid::from_u128(2), 4);
+    deck.add(Uuid::from_u128(3), 3);
+    deck.add(Uuid::from_u128(4), 2);
+    deck.add(Uuid::from_u128(5), 1);
+    // Sideboard cards do not contribute.
+    deck.add_to_sideboard(Uuid::from_u128(6), 1);
+    let lookup = |uuid| set.get(uuid).cloned();
+    assert_eq!(deck.colour_identity(lookup), ColourSet::simic());
+    let mut expected = BTreeMap::new();
+    expected.insert(0, 1);
+    expected.insert(1, 4);
+    expected.insert(2, 5);
+    assert_eq!(deck.mana_curve(lookup), expected);
+    // Cards with the same mana value saturate instead of overflowing.
+    deck.add(Uuid::from_u128(3), u32::MAX);
+    assert_eq!(deck.mana_curve(lookup).get(&2), Some(&u32::MAX));
+}
@@ -0,0 +1,132 @@
+//! The 'format' module provides structures for the classification of game formats.
+
+extern crate serde;
+
+use serde::{Serialize, Deserialize};
+use std::convert::TryFrom;
+use std::fmt;
+
+// The literal representation of all the supported formats.
+const FORMAT_BRAWL: &str = "brawl";
+const FORMAT_COMMANDER: &str = "commander";
+const FORMAT_DUEL: &str = "duel";
+const FORMAT_FUTURE: &str = "future";
+const FORMAT_HISTORIC: &str = "historic";
+const FORMAT_LEGACY: &str = "legacy";
+const FORMAT_MODERN: &str = "modern";
+const FORMAT_OATHBREAKER: &str = "oathbreaker";
+const FORMAT_PAUPER: &str = "pauper";
+const FORMAT_PENNY: &str = "penny";
+const FORMAT_PIONEER: &str = "pioneer";
+const FORMAT_STANDARD: &str = "standard";
+const FORMAT_VINTAGE: &str = "vintage";
+
+/// A 'Format' of the game with its own rules for deck construction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Format {
+    Brawl,
+    Commander,
+    Duel,
+    Future,
+    Historic,
+    Legacy,
+    Modern,
+    Oathbreaker,
+    Pauper,
+    Penny,
+    Pioneer,
+    Standard,
+    Vintage,
+}
+
+impl Format {
+    /// Returns the minimum number of cards in the main deck.
+    pub fn minimum_deck_size(&self) -> u32 {
+        match self {
+            Format::Commander | Format::Duel => 100,
+            _ => 60,
+        }
+    }
+
+    /// Returns the maximum number of copies of a single card, which is not a basic land,
+    /// allowed in a deck including its sideboard.
+    pub fn copy_limit(&self) -> u32 {
+        if self.is_singleton() {
+            1
+        } else {
+            4
+        }
+    }
+
+    /// Checks if decks of the format may only contain a single copy of each card apart from
+    /// basic lands.
+    pub fn is_singleton(&self) -> bool {
+        matches!(self, Format::Brawl | Format::Commander | Format::Duel | Format::Oathbreaker)
+    }
+}
+
+impl From<Format> for &str {
+    fn from(format: Format) -> Self {
+        (&format).into()
+    }
+}
+
+impl From<&Format> for &str {
+    fn from(format: &Format) -> Self {
+        match format {
+            Format::Brawl => FORMAT_BRAWL,
+            Format::Commander => FORMAT_COMMANDER,
+            Format::Duel => FORMAT_DUEL,
+            Format::Future => FORMAT_FUTURE,
+            Format::Historic => FORMAT_HISTORIC,
+            Format::Legacy => FORMAT_LEGACY,
+            Format::Modern => FORMAT_MODERN,
+            Format::Oathbreaker => FORMAT_OATHBREAKER,
+            Format::Pauper => FORMAT_PAUPER,
+            Format::Penny => FORMAT_PENNY,
+            Format::Pioneer => FORMAT_PIONEER,
+            Format::Standard => FORMAT_STANDARD,
+            Format::Vintage => FORMAT_VINTAGE,
+        }
+    }
+}
+
+impl TryFrom<&str> for Format {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            FORMAT_BRAWL => Ok(Format::Brawl),
+            FORMAT_COMMANDER => Ok(Format::Commander),
+            FORMAT_DUEL => Ok(Format::Duel),
+            FORMAT_FUTURE => Ok(Format::Future),
+            FORMAT_HISTORIC => Ok(Format::Historic),
+            FORMAT_LEGACY => Ok(Format::Legacy),
+            FORMAT_MODERN => Ok(Format::Modern),
+            FORMAT_OATHBREAKER => Ok(Format::Oathbreaker),
+            FORMAT_PAUPER => Ok(Format::Pauper),
+            FORMAT_PENNY => Ok(Format::Penny),
+            FORMAT_PIONEER => Ok(Format::Pioneer),
+            FORMAT_STANDARD => Ok(Format::Standard),
+            FORMAT_VINTAGE => Ok(Format::Vintage),
+            _ => Err(format!("{} is not a valid format.", value)),
+        }
+    }
+}
+
+impl TryFrom<String> for Format {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Format::try_from(value.as_str())
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,49 @@
+use super::*;
+use std::convert::TryInto;
+
+#[test]
+/// Tests if the conversion from `Format` to string works as expected.
+fn test_conversion_to_string() {
+    assert_eq!(Into::<&str>::into(Format::Standard), FORMAT_STANDARD);
+    assert_eq!(Into::<&str>::into(Format::Commander), FORMAT_COMMANDER);
+    assert_eq!(Format::Pauper.to_string(), "pauper");
+}
+
+#[test]
+/// Tests if the conversion from string to `Format` works as expected.
+fn test_conversion_from_string() {
+    let formats = vec!(
+        Format::Brawl,
+        Format::Commander,
+        Format::Duel,
+        Format::Future,
+        Format::Historic,
+        Format::Legacy,
+        Format::Modern,
+        Format::Oathbreaker,
+        Format::Pauper,
+        Format::Penny,
+        Format::Pioneer,
+        Format::Standard,
+        Format::Vintage,
+    );
+    for format in formats {
+        assert_eq!(TryInto::<Format>::try_into(Into::<&str>::into(format)), Ok(format));
+        assert_eq!(TryInto::<Format>::try_into(format.to_string()), Ok(format));
+    }
+    assert!(TryInto::<Format>::try_into("oldschool").is_err());
+    assert!(TryInto::<Format>::try_into("Standard").is_err());
+}
+
+#[test]
+/// Tests if the deck construction rules of a `Format` work as expected.
+fn test_deck_construction_rules() {
+    assert_eq!(Format::Modern.minimum_deck_size(), 60);
+    assert_eq!(Format::Modern.copy_limit(), 4);
+    assert!(!Format::Modern.is_singleton());
+    assert_eq!(Format::Commander.minimum_deck_size(), 100);
+    assert_eq!(Format::Commander.copy_limit(), 1);
+    assert!(Format::Commander.is_singleton());
+    assert_eq!(Format::Oathbreaker.minimum_deck_size(), 60);
+    assert_eq!(Format::Oathbreaker.copy_limit(), 1);
+}
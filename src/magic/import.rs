@@ -0,0 +1,399 @@
+//! The 'import' module provides conversion of MTGJSON data into the internal card format.
+
+use super::card::{
+    is_valid_keyrune, link_faces, Card, CardBuilder, CardSet, CardSetBuilder, SetType,
+    DEFAULT_KEYRUNE,
+};
+use super::colour::{Colour, ColourSet, ManaCost};
+use super::language::LocalisedString;
+use super::legality::Legality;
+use super::rarity::Rarity;
+use crate::application::error::PhyrexianError;
+use chrono::NaiveDate;
+use serde::de::{DeserializeSeed, Deserializer, Error, IgnoredAny, MapAccess, Visitor};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use uuid::Uuid;
+
+/// The key of the list of card faces in a card record.
+const JSON_CARD_FACES: &str = "card_faces";
+/// The format of release dates.
+const JSON_DATE_FORMAT: &str = "%Y-%m-%d";
+/// The key of the map of sets in an `AllPrintings` file.
+const JSON_DATA: &str = "data";
+
+#[derive(Debug, Deserialize)]
+/// The content of an MTGJSON `AllPrintings` file.
+struct JsonAllPrintings {
+    data: BTreeMap<String, JsonSet>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// A set as represented by MTGJSON. The cards are kept as raw records, so that a single
+/// invalid card does not prevent the import of the whole set.
+struct JsonSet {
+    block: Option<String>,
+    #[serde(default)]
+    cards: Vec<Value>,
+    code: String,
+    keyrune_code: String,
+    name: String,
+    release_date: String,
+    #[serde(rename = "type")]
+    set_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// A card as represented by MTGJSON.
+struct JsonCard {
+    artist: Option<String>,
+    #[serde(default)]
+    availability: Vec<String>,
+    border_color: String,
+    #[serde(default)]
+    colors: Vec<String>,
+    #[serde(default)]
+    color_identity: Vec<String>,
+    flavor_text: Option<String>,
+    #[serde(default)]
+    legalities: HashMap<String, String>,
+    loyalty: Option<String>,
+    mana_cost: Option<String>,
+    name: String,
+    number: String,
+    #[serde(default)]
+    other_face_ids: Vec<String>,
+    power: Option<String>,
+    rarity: String,
+    set_code: String,
+    text: Option<String>,
+    toughness: Option<String>,
+    #[serde(rename = "type")]
+    type_line: String,
+    uuid: String,
+    #[serde(default)]
+    variations: Vec<String>,
+}
+
+impl TryFrom<JsonCard> for Card {
+    type Error = PhyrexianError;
+
+    fn try_from(json: JsonCard) -> Result<Self, Self::Error> {
+        let mut builder = CardBuilder::default();
+        builder
+            .availability(json.availability)
+            .border_colour(json.border_color)
+            .card_type(LocalisedString::new(json.type_line))
+            .colour(convert_colours(&json.colors)?)
+            .colour_identity(convert_colours(&json.color_identity)?)
+            .faces(convert_uuids(&json.other_face_ids)?)
+            .legality(
+                json.legalities
+                    .into_iter()
+                    .map(|(format, legality)| Legality::try_from(legality).map(|l| (format, l)))
+                    .collect::<Result<BTreeMap<String, Legality>, String>>()?,
+            )
+            .name(LocalisedString::new(json.name))
+            .number(json.number)
+            .rarity(Rarity::from_mtgjson(&json.rarity))
+            .set_code(json.set_code)
+            .uuid(Uuid::parse_str(&json.uuid)?)
+            .variations(convert_uuids(&json.variations)?);
+        if let Some(artist) = json.artist {
+            builder.artist(artist);
+        }
+        if let Some(flavor_text) = json.flavor_text {
+            builder.flavor_text(LocalisedString::new(flavor_text));
+        }
+        if let Some(loyalty) = json.loyalty {
+            builder.loyalty(loyalty);
+        }
+        if let Some(mana_cost) = json.mana_cost {
+            builder.mana_cost(ManaCost::try_from(mana_cost)?);
+        }
+        if let Some(power) = json.power {
+            builder.power(power);
+        }
+        if let Some(text) = json.text {
+            builder.text(LocalisedString::new(text));
+        }
+        if let Some(toughness) = json.toughness {
+            builder.toughness(toughness);
+        }
+        builder.build_validated()
+    }
+}
+
+/// Imports all sets of an MTGJSON `AllPrintings` file. Cards that cannot be converted
+/// are skipped, use [`import_all_printings_with_errors`](import_all_printings_with_errors)
+/// to obtain the causes.
+///
+/// # Parameters
+///
+/// * `path` - the path to the `AllPrintings` file
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or if it or any of its sets is malformed.
+pub fn import_all_printings<P: AsRef<Path>>(path: P) -> Result<Vec<CardSet>, PhyrexianError> {
+    import_all_printings_with_errors(path).map(|(sets, _)| sets)
+}
+
+/// Imports all sets of an MTGJSON `AllPrintings` file. Cards that cannot be converted are
+/// skipped and the causes are returned alongside the sets.
+///
+/// # Parameters
+///
+/// * `path` - the path to the `AllPrintings` file
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or if it or any of its sets is malformed.
+pub fn import_all_printings_with_errors<P: AsRef<Path>>(
+    path: P,
+) -> Result<(Vec<CardSet>, Vec<PhyrexianError>), PhyrexianError> {
+    let reader = BufReader::new(File::open(path)?);
+    let all_printings: JsonAllPrintings = serde_json::from_reader(reader)?;
+    let mut skipped = Vec::new();
+    let sets = all_printings
+        .data
+        .into_values()
+        .map(|set| convert_set(set, &mut skipped))
+        .collect::<Result<Vec<CardSet>, PhyrexianError>>()?;
+    Ok((sets, skipped))
+}
+
+/// Imports all sets of an MTGJSON `AllPrintings` file one after another, passing each set
+/// to the callback as soon as it has been parsed. In contrast to
+/// [`import_all_printings`](import_all_printings) only a single set is kept in memory at
+/// a time. The sets are passed in the order they appear in the file. Cards that cannot be
+/// converted are skipped.
+///
+/// # Parameters
+///
+/// * `path` - the path to the `AllPrintings` file
+/// * `on_set` - the callback receiving each set
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or if it or any of its sets is malformed.
+/// Sets preceding the malformed one have already been passed to the callback.
+pub fn stream_all_printings<P, F>(path: P, mut on_set: F) -> Result<(), PhyrexianError>
+where
+    P: AsRef<Path>,
+    F: FnMut(CardSet),
+{
+    let reader = BufReader::new(File::open(path)?);
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let mut failure = None;
+    let result = deserializer.deserialize_map(AllPrintingsStream(SetStream {
+        on_set: &mut on_set,
+        failure: &mut failure,
+    }));
+    if let Some(failure) = failure {
+        return Err(failure);
+    }
+    result?;
+    deserializer.end()?;
+    Ok(())
+}
+
+/// Visits an MTGJSON `AllPrintings` file and streams the sets of its `data` map.
+struct AllPrintingsStream<'a, F>(SetStream<'a, F>);
+
+impl<'de, F: FnMut(CardSet)> Visitor<'de> for AllPrintingsStream<'_, F> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an MTGJSON AllPrintings file")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut sets = Some(self.0);
+        while let Some(key) = map.next_key::<String>()? {
+            match sets.take() {
+                Some(stream) if key == JSON_DATA => map.next_value_seed(stream)?,
+                other => {
+                    sets = other;
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        match sets {
+            Some(_) => Err(A::Error::missing_field(JSON_DATA)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Visits the `data` map of an MTGJSON `AllPrintings` file and passes each set to the
+/// callback after converting it. The cause of a set that cannot be converted is stored
+/// as failure.
+struct SetStream<'a, F> {
+    on_set: &'a mut F,
+    failure: &'a mut Option<PhyrexianError>,
+}
+
+impl<'de, F: FnMut(CardSet)> DeserializeSeed<'de> for SetStream<'_, F> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, F: FnMut(CardSet)> Visitor<'de> for SetStream<'_, F> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of MTGJSON sets")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        while let Some((code, json)) = map.next_entry::<String, JsonSet>()? {
+            match convert_set(json, &mut Vec::new()) {
+                Ok(set) => (self.on_set)(set),
+                Err(err) => {
+                    *self.failure = Some(err);
+                    return Err(A::Error::custom(format!("The set {} is malformed.", code)));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Converts an MTGJSON set into a [`CardSet`](CardSet). Cards that cannot be converted are
+/// skipped and the causes are added to the list of skipped cards. Sets with a keyrune code
+/// that is not alphanumeric are shown with the [`DEFAULT_KEYRUNE`](DEFAULT_KEYRUNE) icon.
+///
+/// # Parameters
+///
+/// * `json` - the set to convert
+/// * `skipped` - the causes of skipped cards
+fn convert_set(
+    json: JsonSet,
+    skipped: &mut Vec<PhyrexianError>,
+) -> Result<CardSet, PhyrexianError> {
+    let mut keyrune = json.keyrune_code.to_lowercase();
+    if !is_valid_keyrune(&keyrune) {
+        keyrune = DEFAULT_KEYRUNE.to_string();
+    }
+    let mut builder = CardSetBuilder::default();
+    builder
+        .code(json.code)
+        .keyrune(keyrune)
+        .name(LocalisedString::new(json.name))
+        .release_date(NaiveDate::parse_from_str(&json.release_date, JSON_DATE_FORMAT)?);
+    if let Some(block) = json.block {
+        builder.block(LocalisedString::new(block));
+    }
+    if let Some(set_type) = json.set_type.and_then(|set_type| SetType::try_from(set_type).ok()) {
+        builder.set_type(set_type);
+    }
+    let mut set = builder.build()?;
+    for record in &json.cards {
+        match split_faces(record) {
+            Ok(cards) => cards.into_iter().for_each(|card| {
+                set.insert(card);
+            }),
+            Err(err) => skipped.push(err),
+        }
+    }
+    Ok(set)
+}
+
+/// Converts a list of MTGJSON colours into a [`ColourSet`](ColourSet).
+///
+/// # Parameters
+///
+/// * `colours` - the colours to convert
+fn convert_colours(colours: &[String]) -> Result<ColourSet, String> {
+    colours.iter().map(|colour| Colour::try_from(colour.as_str())).collect()
+}
+
+/// Converts a list of MTGJSON UUIDs into [`UUID`](uuid::Uuid)s.
+///
+/// # Parameters
+///
+/// * `uuids` - the UUIDs to convert
+fn convert_uuids(uuids: &[String]) -> Result<Vec<Uuid>, uuid::Error> {
+    uuids.iter().map(|uuid| Uuid::parse_str(uuid)).collect()
+}
+
+/// Converts a single MTGJSON card record into a [`Card`](Card).
+///
+/// # Parameters
+///
+/// * `value` - the card record
+fn card_from_json(value: &Value) -> Result<Card, PhyrexianError> {
+    let json: JsonCard = serde_json::from_value(value.clone())?;
+    Card::try_from(json)
+}
+
+/// Converts an MTGJSON card record into [`Card`](Card)s. If the record contains a
+/// `card_faces` list, one [`Card`](Card) is created per face and the faces are linked to
+/// each other. Each face inherits all values of the record that it does not define itself,
+/// but must define its own UUID. Otherwise a single [`Card`](Card) is created.
+///
+/// # Parameters
+///
+/// * `value` - the card record
+///
+/// # Errors
+///
+/// Returns an error if the record or any of its faces cannot be converted or if multiple
+/// faces share the same UUID.
+pub fn split_faces(value: &Value) -> Result<Vec<Card>, PhyrexianError> {
+    let faces = match value.get(JSON_CARD_FACES).and_then(Value::as_array) {
+        Some(faces) => faces,
+        None => return Ok(vec!(card_from_json(value)?)),
+    };
+    let mut cards = faces
+        .iter()
+        .map(|face| card_from_json(&merge_face(value, face)?))
+        .collect::<Result<Vec<Card>, PhyrexianError>>()?;
+    let uuids: HashSet<Uuid> = cards.iter().map(Card::uuid).collect();
+    if uuids.len() != cards.len() {
+        return Err(PhyrexianError::ConversionError(format!(
+            "The faces of card {} do not have distinct UUIDs.",
+            cards[0].name().get_default()
+        )));
+    }
+    link_faces(&mut cards);
+    Ok(cards)
+}
+
+/// Combines a card record with one of its faces, where the values of the face take
+/// precedence.
+///
+/// # Parameters
+///
+/// * `card` - the card record
+/// * `face` - the face of the card
+fn merge_face(card: &Value, face: &Value) -> Result<Value, PhyrexianError> {
+    match (card, face) {
+        (Value::Object(card), Value::Object(face)) => {
+            let mut merged = card.clone();
+            merged.remove(JSON_CARD_FACES);
+            for (key, value) in face {
+                merged.insert(key.clone(), value.clone());
+            }
+            Ok(Value::Object(merged))
+        },
+        _ => Err(PhyrexianError::ConversionError(
+            "Cards and card faces must be JSON objects.".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,180 @@
+use super::*;
+use crate::magic::card::SetCode;
+use serde_json::json;
+
+/// Returns a minimal MTGJSON card record.
+fn card_json() -> Value {
+    json!({
+        "artist": "Test Artist",
+        "availability": ["paper"],
+        "borderColor": "black",
+        "colors": ["G"],
+        "colorIdentity": ["G", "U"],
+        "legalities": {"commander": "Legal", "vintage": "Restricted"},
+        "manaCost": "{1}{G}",
+        "name": "Test Card",
+        "number": "12",
+        "power": "2",
+        "rarity": "uncommon",
+        "setCode": "TST",
+        "text": "{U}: Untap this creature.",
+        "toughness": "3",
+        "type": "Creature — Elf Druid",
+        "uuid": "00000000-0000-0000-0000-000000000001",
+        "variations": ["00000000-0000-0000-0000-000000000003"]
+    })
+}
+
+#[test]
+/// Tests if a card record without faces is converted into a single `Card`.
+fn test_split_faces_single() {
+    let cards = split_faces(&card_json()).unwrap();
+    assert_eq!(cards.len(), 1);
+    let card = &cards[0];
+    assert_eq!(card.uuid(), Uuid::from_u128(1));
+    assert_eq!(card.name().get_default(), "Test Card");
+    assert_eq!(card.artist(), &Some("Test Artist".to_string()));
+    assert_eq!(card.rarity(), Rarity::Uncommon);
+    assert_eq!(card.card_type().get_default(), "Creature — Elf Druid");
+    assert_eq!(card.mana_cost().as_ref().unwrap().to_string(), "{1}{G}");
+    assert!(card.colour().has(Colour::Green));
+    assert_eq!(card.colour_identity().length(), 2);
+    assert_eq!(card.legality("vintage".to_string()), Legality::Restricted);
+    assert!(card.faces().is_empty());
+    assert_eq!(card.variations(), &vec!(Uuid::from_u128(3)));
+}
+
+#[test]
+/// Tests if a double faced card record is converted into two linked `Card`s.
+fn test_split_faces_double_faced() {
+    let mut json = card_json();
+    json[JSON_CARD_FACES] = json!([
+        {"name": "Front", "uuid": "00000000-0000-0000-0000-000000000001"},
+        {
+            "name": "Back",
+            "manaCost": null,
+            "colors": ["U"],
+            "uuid": "00000000-0000-0000-0000-000000000002"
+        }
+    ]);
+    let cards = split_faces(&json).unwrap();
+    assert_eq!(cards.len(), 2);
+    let (front, back) = (&cards[0], &cards[1]);
+    assert_eq!(front.name().get_default(), "Front");
+    assert_eq!(back.name().get_default(), "Back");
+    assert_eq!(front.faces(), &vec!(back.uuid()));
+    assert_eq!(back.faces(), &vec!(front.uuid()));
+    assert!(front.mana_cost().is_some());
+    assert!(back.mana_cost().is_none());
+    assert!(back.colour().has(Colour::Blue));
+    // Values not defined by a face are inherited from the card.
+    assert_eq!(back.number(), "12");
+}
+
+#[test]
+/// Tests if faces sharing a UUID are rejected.
+fn test_split_faces_duplicate_uuid() {
+    let mut json = card_json();
+    json[JSON_CARD_FACES] = json!([{"name": "Front"}, {"name": "Back"}]);
+    assert!(split_faces(&json).is_err());
+}
+
+/// Returns a minimal MTGJSON `AllPrintings` record with two sets, one of which contains an
+/// invalid card.
+fn all_printings_json() -> Value {
+    let mut second_card = card_json();
+    second_card["uuid"] = json!("00000000-0000-0000-0000-000000000002");
+    second_card["setCode"] = json!("TS2");
+    let mut invalid_card = card_json();
+    invalid_card["uuid"] = json!("not a uuid");
+    json!({
+        "meta": {"date": "2021-01-01", "version": "5.0.0"},
+        "data": {
+            "TST": {
+                "block": "Test Block",
+                "cards": [card_json()],
+                "code": "TST",
+                "keyruneCode": "TST",
+                "name": "Test Set",
+                "releaseDate": "2020-09-25",
+                "type": "expansion"
+            },
+            "TS2": {
+                "cards": [second_card, invalid_card],
+                "code": "TS2",
+                "keyruneCode": "TST",
+                "name": "Test Set 2",
+                "releaseDate": "2021-02-05"
+            }
+        }
+    })
+}
+
+#[test]
+/// Tests if sets with an invalid keyrune code are imported with the default keyrune code.
+fn test_convert_set_invalid_keyrune() {
+    for keyrune in ["", "T-S", "TS 2"] {
+        let mut json = all_printings_json()["data"]["TS2"].clone();
+        json["keyruneCode"] = json!(keyrune);
+        let mut skipped = Vec::new();
+        let set = convert_set(serde_json::from_value(json).unwrap(), &mut skipped).unwrap();
+        assert_eq!(set.keyrune(), DEFAULT_KEYRUNE);
+        assert_eq!(set.cards().len(), 1);
+    }
+}
+
+#[test]
+/// Tests if the `import_all_printings` function works as expected.
+fn test_import_all_printings() {
+    let path = std::env::temp_dir().join(format!(
+        "phyrexian_library_{}_all_printings.json",
+        std::process::id()
+    ));
+    std::fs::write(&path, all_printings_json().to_string()).unwrap();
+    let (sets, skipped) = import_all_printings_with_errors(&path).unwrap();
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(sets.len(), 2);
+    let second = &sets[0];
+    assert_eq!(second.code(), "TS2");
+    assert_eq!(second.block(), &None);
+    assert_eq!(second.set_type(), &None);
+    assert_eq!(second.cards().len(), 1);
+    assert_eq!(second.release_date(), &NaiveDate::from_ymd_opt(2021, 2, 5).unwrap());
+    let first = &sets[1];
+    assert_eq!(first.code(), "TST");
+    assert_eq!(first.name().get_default(), "Test Set");
+    assert_eq!(first.block().as_ref().unwrap().get_default(), "Test Block");
+    assert_eq!(first.set_type(), &Some(SetType::Expansion));
+    assert_eq!(first.keyrune(), "tst");
+    let card = first.get(Uuid::from_u128(1)).unwrap();
+    assert_eq!(card.mana_cost().as_ref().unwrap().to_string(), "{1}{G}");
+    assert_eq!(card.legality("commander".to_string()), Legality::Legal);
+    assert_eq!(import_all_printings(&path).unwrap().len(), 2);
+    std::fs::remove_file(&path).unwrap();
+    assert!(import_all_printings(&path).is_err());
+}
+
+#[test]
+/// Tests if the `stream_all_printings` function works as expected.
+fn test_stream_all_printings() {
+    let path = std::env::temp_dir().join(format!(
+        "phyrexian_library_{}_stream_all_printings.json",
+        std::process::id()
+    ));
+    std::fs::write(&path, all_printings_json().to_string()).unwrap();
+    let mut codes = Vec::new();
+    stream_all_printings(&path, |set| codes.push((set.code().clone(), set.cards().len())))
+        .unwrap();
+    assert_eq!(codes, vec!((SetCode::from("TS2"), 1), (SetCode::from("TST"), 1)));
+    // Malformed sets and missing data are reported.
+    let mut malformed = all_printings_json();
+    malformed["data"]["TST"]["releaseDate"] = json!("not a date");
+    std::fs::write(&path, malformed.to_string()).unwrap();
+    let mut streamed = 0;
+    assert!(stream_all_printings(&path, |_| streamed += 1).is_err());
+    assert_eq!(streamed, 1);
+    std::fs::write(&path, json!({"meta": {}}).to_string()).unwrap();
+    assert!(stream_all_printings(&path, |_| {}).is_err());
+    std::fs::remove_file(&path).unwrap();
+    assert!(stream_all_printings(&path, |_| {}).is_err());
+}
@@ -7,6 +7,7 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fmt;
+use std::str::FromStr;
 
 // The literal representation of all the supported languages.
 const LANGUAGE_ANCIENT_GREEK: &str = "Ancient Greek";
@@ -28,7 +29,7 @@ const LANGUAGE_SANSKRIT: &str = "Sanskrit";
 const LANGUAGE_SPANISH: &str = "Spanish";
 
 /// The 'Language' of a Magic card.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Language {
     /// Ancient greek.
     AncientGreek,
@@ -39,6 +40,7 @@ pub enum Language {
     /// Traditional chinese.
     ChineseTraditional,
     /// American english.
+    #[default]
     EnglishAmerican,
     /// French.
     French,
@@ -89,11 +91,10 @@ impl Language {
             Language::Spanish => "es",
         }
     }
-}
 
-impl Default for Language {
-    fn default() -> Self {
-        Language::EnglishAmerican
+    /// Checks if the language is written from right to left.
+    pub fn is_rtl(&self) -> bool {
+        matches!(self, Language::Arabic | Language::Hebrew)
     }
 }
 
@@ -162,6 +163,14 @@ impl TryFrom<String> for Language {
     }
 }
 
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Language::try_from(value)
+    }
+}
+
 impl PartialOrd for Language {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -170,7 +179,7 @@ impl PartialOrd for Language {
 
 impl Ord for Language {
     fn cmp(&self, other: &Self) -> Ordering {
-        Into::<&str>::into(self).cmp(&Into::<&str>::into(other))
+        Into::<&str>::into(self).cmp(Into::<&str>::into(other))
     }
 }
 
@@ -245,6 +254,45 @@ impl LocalisedString {
         self.content.insert(language, value.into())
     }
 
+    /// Removes the string in the specified ['Language'] and returns it if it was set.
+    /// The string in the default ['Language'] can never be removed, as every localised
+    /// string must have a default value. Trying to do so leaves the localised string
+    /// unchanged and returns `None`.
+    ///
+    /// # Parameters
+    ///
+    /// * language - the ['Language'] to remove the string in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::language::{Language, LocalisedString};
+    ///
+    /// let mut localised = LocalisedString::new("Default");
+    /// localised.set(Language::German, "Irgendetwas");
+    /// assert_eq!(Some("Irgendetwas".to_string()), localised.remove(Language::German));
+    /// assert_eq!(None, localised.remove(Language::default()));
+    /// assert_eq!("Default", localised.get_default());
+    /// ```
+    ///
+    /// ['Language']: ./enum.Language.html
+    pub fn remove(&mut self, language: Language) -> Option<String> {
+        if language == Language::default() {
+            None
+        } else {
+            self.content.remove(&language)
+        }
+    }
+
+    /// Returns all ['Language']s the string is available in sorted by their ordering.
+    ///
+    /// ['Language']: ./enum.Language.html
+    pub fn languages(&self) -> Vec<Language> {
+        let mut languages: Vec<Language> = self.content.keys().copied().collect();
+        languages.sort();
+        languages
+    }
+
     /// Returns the string in the default ['Language'].
     ///
     /// ['Language']: ./enum.Language.html
@@ -292,6 +340,56 @@ impl LocalisedString {
             .map_or(self.get_default(), |value| value.as_str())
     }
 
+    /// Returns the string in the first ['Language'] of the preferences it is set in,
+    /// otherwise returns the default.
+    ///
+    /// # Parameters
+    ///
+    /// * preferences - the ['Language']s to try in order of preference
+    ///
+    /// ```
+    /// use phyrexian_library::magic::language::{Language, LocalisedString};
+    ///
+    /// let mut localised = LocalisedString::new("Default");
+    /// localised.set(Language::German, "Irgendetwas");
+    /// let preferences = [Language::French, Language::German];
+    /// assert_eq!("Irgendetwas", localised.get_with_fallback(&preferences));
+    /// assert_eq!("Default", localised.get_with_fallback(&[Language::French]));
+    /// ```
+    ///
+    /// ['Language']: ./enum.Language.html
+    pub fn get_with_fallback(&self, preferences: &[Language]) -> &str {
+        preferences
+            .iter()
+            .find_map(|language| self.get_localised(*language))
+            .unwrap_or_else(|| self.get_default())
+    }
+
+    /// Copies all localisations of the other string into this string and returns the
+    /// number of localisations that were overwritten. As both strings have a default
+    /// value, the default value of this string is always overwritten by the other one.
+    ///
+    /// # Parameters
+    ///
+    /// * other - the localised string to merge into this string
+    ///
+    /// ```
+    /// use phyrexian_library::magic::language::{Language, LocalisedString};
+    ///
+    /// let mut localised = LocalisedString::new("Default");
+    /// let mut other = LocalisedString::new("Other default");
+    /// other.set(Language::German, "Irgendetwas");
+    /// assert_eq!(1, localised.merge(other));
+    /// assert_eq!("Other default", localised.get_default());
+    /// assert_eq!(Some("Irgendetwas"), localised.get_localised(Language::German));
+    /// ```
+    pub fn merge(&mut self, other: LocalisedString) -> usize {
+        other.content
+            .into_iter()
+            .filter_map(|(language, value)| self.content.insert(language, value))
+            .count()
+    }
+
     /// Checks if any of the localisation contains the specified pattern.
     /// 
     /// # Parameters
@@ -310,7 +408,7 @@ impl PartialOrd for LocalisedString {
 
 impl Ord for LocalisedString {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.get_default().cmp(&other.get_default())
+        self.get_default().cmp(other.get_default())
     }
 }
 
@@ -106,4 +106,21 @@ fn test_conversion_to_code() {
     assert_eq!(Language::Russian.code(), "ru");
     assert_eq!(Language::Sanskrit.code(), "sa");
     assert_eq!(Language::Spanish.code(), "es");
-}
\ No newline at end of file
+}
+
+#[test]
+/// Tests if the writing direction of `Language` is determined correctly.
+fn test_is_rtl() {
+    assert!(Language::Arabic.is_rtl());
+    assert!(Language::Hebrew.is_rtl());
+    assert!(!Language::EnglishAmerican.is_rtl());
+    assert!(!Language::Japanese.is_rtl());
+    assert!(!Language::Phyrexian.is_rtl());
+}
+
+#[test]
+/// Tests if `Language` can be parsed from a string.
+fn test_parse() {
+    assert_eq!("German".parse::<Language>(), Ok(Language::German));
+    assert!("Klingon".parse::<Language>().is_err());
+}
@@ -89,3 +89,78 @@ fn test_get_localised_or_default() {
     assert_eq!(test_default, test_localised_string.get_localised_or_default(Language::default()));
     assert_eq!(test_russian, test_localised_string.get_localised_or_default(Language::Russian));
 }
+
+#[test]
+/// Tests if the `remove` method of `LocalisedString` works as expected.
+fn test_remove() {
+    let test_default = "Test default";
+    let test_russian = "Test russian";
+    let mut test_localised_string = LocalisedString::new(test_default);
+    assert_eq!(None, test_localised_string.remove(Language::Russian));
+    test_localised_string.set(Language::Russian, test_russian);
+    assert_eq!(Some(test_russian.to_string()), test_localised_string.remove(Language::Russian));
+    assert_eq!(None, test_localised_string.get_localised(Language::Russian));
+    assert_eq!(None, test_localised_string.remove(Language::default()));
+    assert_eq!(test_default, test_localised_string.get_default());
+}
+
+#[test]
+/// Tests if the `languages` method of `LocalisedString` works as expected.
+fn test_languages() {
+    let mut test_localised_string = LocalisedString::new("Test default");
+    assert_eq!(vec!(Language::default()), test_localised_string.languages());
+    test_localised_string.set(Language::Russian, "Test russian");
+    test_localised_string.set(Language::Arabic, "Test arabic");
+    test_localised_string.set(Language::German, "Test german");
+    let mut expected = vec!(
+        Language::Arabic,
+        Language::German,
+        Language::Russian,
+        Language::default(),
+    );
+    expected.sort();
+    assert_eq!(expected, test_localised_string.languages());
+    test_localised_string.remove(Language::German);
+    assert!(!test_localised_string.languages().contains(&Language::German));
+}
+
+#[test]
+/// Tests if the `get_with_fallback` method of `LocalisedString` works as expected.
+fn test_get_with_fallback() {
+    let test_default = "Test default";
+    let test_german = "Test german";
+    let test_italian = "Test italian";
+    let mut test_localised_string = LocalisedString::new(test_default);
+    assert_eq!(test_default, test_localised_string.get_with_fallback(&[]));
+    test_localised_string.set(Language::German, test_german);
+    test_localised_string.set(Language::Italian, test_italian);
+    assert_eq!(
+        test_german,
+        test_localised_string.get_with_fallback(&[Language::French, Language::German])
+    );
+    assert_eq!(
+        test_italian,
+        test_localised_string.get_with_fallback(&[Language::Italian, Language::German])
+    );
+    assert_eq!(
+        test_default,
+        test_localised_string.get_with_fallback(&[Language::French, Language::Russian])
+    );
+}
+
+#[test]
+/// Tests if the `merge` method of `LocalisedString` works as expected.
+fn test_merge() {
+    let mut test_localised_string = LocalisedString::new("Test default");
+    test_localised_string.set(Language::German, "Test german");
+    test_localised_string.set(Language::Italian, "Test italian");
+    let mut other = LocalisedString::new("Other default");
+    other.set(Language::German, "Other german");
+    other.set(Language::Russian, "Other russian");
+    assert_eq!(2, test_localised_string.merge(other));
+    assert_eq!("Other default", test_localised_string.get_default());
+    assert_eq!(Some("Other german"), test_localised_string.get_localised(Language::German));
+    assert_eq!(Some("Test italian"), test_localised_string.get_localised(Language::Italian));
+    assert_eq!(Some("Other russian"), test_localised_string.get_localised(Language::Russian));
+    assert_eq!(4, test_localised_string.languages().len());
+}
@@ -6,17 +6,24 @@ use serde::{Serialize, Deserialize};
 use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt;
+use std::str::FromStr;
 
 // The literal representation of all the supported legalities.
 const LEGALITY_BANNED: &str = "Banned";
 const LEGALITY_LEGAL: &str = "Legal";
 const LEGALITY_NOT_LEGAL: &str = "Not Legal";
 const LEGALITY_RESTRICTED: &str = "Restricted";
+// The literal representation of all the supported legalities as used by MTGJSON.
+const LEGALITY_MTGJSON_BANNED: &str = "banned";
+const LEGALITY_MTGJSON_LEGAL: &str = "legal";
+const LEGALITY_MTGJSON_NOT_LEGAL: &str = "not_legal";
+const LEGALITY_MTGJSON_RESTRICTED: &str = "restricted";
 
 /// The 'Legality' of a Magic card.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Legality {
     Banned,
+    #[default]
     Legal,
     NotLegal,
     Restricted,
@@ -34,12 +41,6 @@ impl Legality {
     }
 }
 
-impl Default for Legality {
-    fn default() -> Self {
-        Legality::Legal
-    }
-}
-
 impl PartialOrd for Legality {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -74,10 +75,10 @@ impl TryFrom<&str> for Legality {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value {
-            LEGALITY_BANNED => Ok(Legality::Banned),
-            LEGALITY_LEGAL => Ok(Legality::Legal),
-            LEGALITY_NOT_LEGAL => Ok(Legality::NotLegal),
-            LEGALITY_RESTRICTED => Ok(Legality::Restricted),
+            LEGALITY_BANNED | LEGALITY_MTGJSON_BANNED => Ok(Legality::Banned),
+            LEGALITY_LEGAL | LEGALITY_MTGJSON_LEGAL => Ok(Legality::Legal),
+            LEGALITY_NOT_LEGAL | LEGALITY_MTGJSON_NOT_LEGAL => Ok(Legality::NotLegal),
+            LEGALITY_RESTRICTED | LEGALITY_MTGJSON_RESTRICTED => Ok(Legality::Restricted),
             _ => Err(format!("{} is not a valid legality.", value)),
         }
     }
@@ -91,8 +92,19 @@ impl TryFrom<String> for Legality {
     }
 }
 
+impl FromStr for Legality {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Legality::try_from(value)
+    }
+}
+
 impl fmt::Display for Legality {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self.into())
     }
 }
+
+#[cfg(test)]
+mod test;
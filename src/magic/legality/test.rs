@@ -0,0 +1,35 @@
+use super::*;
+use std::convert::TryInto;
+
+#[test]
+/// Tests if the conversion from string to `Legality` works as expected.
+fn test_conversion_from_string() {
+    assert_eq!(TryInto::<Legality>::try_into(LEGALITY_BANNED), Ok(Legality::Banned));
+    assert_eq!(TryInto::<Legality>::try_into(LEGALITY_LEGAL), Ok(Legality::Legal));
+    assert_eq!(TryInto::<Legality>::try_into(LEGALITY_NOT_LEGAL), Ok(Legality::NotLegal));
+    assert_eq!(
+        TryInto::<Legality>::try_into(LEGALITY_RESTRICTED.to_string()),
+        Ok(Legality::Restricted)
+    );
+    assert!(TryInto::<Legality>::try_into("Suspended").is_err());
+}
+
+#[test]
+/// Tests if the conversion from MTGJSON strings to `Legality` works as expected.
+fn test_conversion_from_mtgjson_string() {
+    assert_eq!(TryInto::<Legality>::try_into("banned"), Ok(Legality::Banned));
+    assert_eq!(TryInto::<Legality>::try_into("legal"), Ok(Legality::Legal));
+    assert_eq!(TryInto::<Legality>::try_into("not_legal"), Ok(Legality::NotLegal));
+    assert_eq!(TryInto::<Legality>::try_into("restricted"), Ok(Legality::Restricted));
+    // The display representation is unchanged.
+    assert_eq!(Legality::NotLegal.to_string(), LEGALITY_NOT_LEGAL);
+    assert_eq!(Legality::Restricted.to_string(), LEGALITY_RESTRICTED);
+}
+
+#[test]
+/// Tests if `Legality` can be parsed from a string.
+fn test_parse() {
+    assert_eq!("Restricted".parse::<Legality>(), Ok(Legality::Restricted));
+    assert_eq!("not_legal".parse::<Legality>(), Ok(Legality::NotLegal));
+    assert!("Suspended".parse::<Legality>().is_err());
+}
@@ -3,9 +3,22 @@
 use crate::magic::language::Language;
 
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
 use uuid::Uuid;
 
-#[derive(Builder, Clone, Debug, CopyGetters, Getters, Serialize, Deserialize)]
+// The literal representation of all the supported conditions.
+const CONDITION_MINT: &str = "Mint";
+const CONDITION_NEAR_MINT: &str = "Near Mint";
+const CONDITION_EXCELLENT: &str = "Excellent";
+const CONDITION_GOOD: &str = "Good";
+const CONDITION_LIGHT_PLAYED: &str = "Light Played";
+const CONDITION_PLAYED: &str = "Played";
+const CONDITION_POOR: &str = "Poor";
+
+#[derive(Builder, Clone, Debug, CopyGetters, Getters, Setters, Serialize, Deserialize)]
+#[builder(build_fn(validate = "Self::validate"))]
 /// An actual physical card.
 pub struct PhysicalCard {
     #[getset(get = "pub", set = "pub")]
@@ -28,6 +41,24 @@ pub struct PhysicalCard {
     // The language of the card.
     language: Language,
 
+    #[getset(get_copy = "pub", set = "pub")]
+    #[builder(default = "Condition::NearMint")]
+    #[serde(default)]
+    /// The condition of the card.
+    condition: Condition,
+
+    #[getset(get_copy = "pub")]
+    #[builder(default = "1")]
+    #[serde(default = "default_quantity")]
+    /// The number of copies of the card. This is never zero.
+    quantity: u32,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    #[builder(setter(into, strip_option), default)]
+    #[serde(default)]
+    /// The optional value of a single copy of the card in cents.
+    value_cents: Option<u64>,
+
     #[getset(get = "pub", set = "pub")]
     #[builder(setter(into, strip_option), default)]
     /// An optional comment on by whom the card was signed.
@@ -51,3 +82,132 @@ pub struct PhysicalCard {
     /// The UUID of the card.
     uuid: Uuid,
 }
+
+impl PhysicalCard {
+    /// Sets the number of copies of the card.
+    ///
+    /// # Parameters
+    ///
+    /// * `quantity` - the number of copies
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the card unchanged if the quantity is zero.
+    pub fn set_quantity(&mut self, quantity: u32) -> Result<&mut Self, String> {
+        if quantity == 0 {
+            return Err("The quantity of a physical card must not be zero.".to_string());
+        }
+        self.quantity = quantity;
+        Ok(self)
+    }
+}
+
+impl PhysicalCardBuilder {
+    /// Checks if the values set on the builder describe a valid [`PhysicalCard`](PhysicalCard).
+    fn validate(&self) -> Result<(), String> {
+        match self.quantity {
+            Some(0) => Err("The quantity of a physical card must not be zero.".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns the quantity of physical cards stored before the quantity was tracked.
+fn default_quantity() -> u32 {
+    1
+}
+
+/// The 'Condition' of a physical card. Conditions are ordered from the
+/// worst ([`Poor`](Condition::Poor)) to the best ([`Mint`](Condition::Mint)) condition.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Condition {
+    Mint,
+    #[default]
+    NearMint,
+    Excellent,
+    Good,
+    LightPlayed,
+    Played,
+    Poor,
+}
+
+impl Condition {
+    /// Returns a number for ordering of conditions.
+    fn ordering_number(&self) -> u8 {
+        match self {
+            Condition::Poor => 0,
+            Condition::Played => 1,
+            Condition::LightPlayed => 2,
+            Condition::Good => 3,
+            Condition::Excellent => 4,
+            Condition::NearMint => 5,
+            Condition::Mint => 6,
+        }
+    }
+}
+
+impl PartialOrd for Condition {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Condition {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ordering_number().cmp(&other.ordering_number())
+    }
+}
+
+impl From<Condition> for &str {
+    fn from(condition: Condition) -> Self {
+        (&condition).into()
+    }
+}
+
+impl From<&Condition> for &str {
+    fn from(condition: &Condition) -> Self {
+        match condition {
+            Condition::Mint => CONDITION_MINT,
+            Condition::NearMint => CONDITION_NEAR_MINT,
+            Condition::Excellent => CONDITION_EXCELLENT,
+            Condition::Good => CONDITION_GOOD,
+            Condition::LightPlayed => CONDITION_LIGHT_PLAYED,
+            Condition::Played => CONDITION_PLAYED,
+            Condition::Poor => CONDITION_POOR,
+        }
+    }
+}
+
+impl TryFrom<&str> for Condition {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            CONDITION_MINT => Ok(Condition::Mint),
+            CONDITION_NEAR_MINT => Ok(Condition::NearMint),
+            CONDITION_EXCELLENT => Ok(Condition::Excellent),
+            CONDITION_GOOD => Ok(Condition::Good),
+            CONDITION_LIGHT_PLAYED => Ok(Condition::LightPlayed),
+            CONDITION_PLAYED => Ok(Condition::Played),
+            CONDITION_POOR => Ok(Condition::Poor),
+            _ => Err(format!("{} is not a valid condition.", value)),
+        }
+    }
+}
+
+impl TryFrom<String> for Condition {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Condition::try_from(value.as_str())
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,86 @@
+use super::*;
+use std::convert::TryInto;
+
+/// Returns a builder for a `PhysicalCard` with all required fields set.
+fn physical_card_builder() -> PhysicalCardBuilder {
+    let mut builder = PhysicalCardBuilder::default();
+    builder
+        .template(Uuid::from_u128(1))
+        .uuid(Uuid::from_u128(2));
+    builder
+}
+
+#[test]
+/// Tests if the ordering of `Condition` works as expected.
+fn test_condition_ordering() {
+    let mut unordered = vec!(
+        Condition::NearMint,
+        Condition::Poor,
+        Condition::Mint,
+        Condition::Good,
+        Condition::Played,
+        Condition::Excellent,
+        Condition::LightPlayed,
+    );
+    let ordered = vec!(
+        Condition::Poor,
+        Condition::Played,
+        Condition::LightPlayed,
+        Condition::Good,
+        Condition::Excellent,
+        Condition::NearMint,
+        Condition::Mint,
+    );
+    assert_ne!(unordered, ordered);
+    unordered.sort();
+    assert_eq!(unordered, ordered);
+}
+
+#[test]
+/// Tests if the conversion between `Condition` and string works as expected.
+fn test_condition_conversion() {
+    assert_eq!(Into::<&str>::into(Condition::Mint), CONDITION_MINT);
+    assert_eq!(Into::<&str>::into(Condition::LightPlayed), CONDITION_LIGHT_PLAYED);
+    assert_eq!(Condition::NearMint.to_string(), "Near Mint");
+    assert_eq!(TryInto::<Condition>::try_into(CONDITION_POOR), Ok(Condition::Poor));
+    assert_eq!(
+        TryInto::<Condition>::try_into(CONDITION_NEAR_MINT.to_string()),
+        Ok(Condition::NearMint)
+    );
+    assert!(TryInto::<Condition>::try_into("Damaged").is_err());
+}
+
+#[test]
+/// Tests if the `condition` field of `PhysicalCard` works as expected.
+fn test_condition() {
+    let mut card = physical_card_builder().build().unwrap();
+    assert_eq!(card.condition(), Condition::NearMint);
+    card.set_condition(Condition::Played);
+    assert_eq!(card.condition(), Condition::Played);
+    let card = physical_card_builder().condition(Condition::Mint).build().unwrap();
+    assert_eq!(card.condition(), Condition::Mint);
+}
+
+#[test]
+/// Tests if the `quantity` field of `PhysicalCard` works as expected.
+fn test_quantity() {
+    let mut card = physical_card_builder().build().unwrap();
+    assert_eq!(card.quantity(), 1);
+    assert!(card.set_quantity(4).is_ok());
+    assert_eq!(card.quantity(), 4);
+    assert!(card.set_quantity(0).is_err());
+    assert_eq!(card.quantity(), 4);
+    assert_eq!(physical_card_builder().quantity(3).build().unwrap().quantity(), 3);
+    assert!(physical_card_builder().quantity(0).build().is_err());
+}
+
+#[test]
+/// Tests if the `value_cents` field of `PhysicalCard` works as expected.
+fn test_value_cents() {
+    let mut card = physical_card_builder().build().unwrap();
+    assert_eq!(card.value_cents(), None);
+    card.set_value_cents(Some(1250));
+    assert_eq!(card.value_cents(), Some(1250));
+    let card = physical_card_builder().value_cents(99u64).build().unwrap();
+    assert_eq!(card.value_cents(), Some(99));
+}
@@ -6,6 +6,7 @@ use serde::{Serialize, Deserialize};
 use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt;
+use std::str::FromStr;
 
 // The literal representation of all the supported rarities.
 const RARITY_COMMON: &str = "common";
@@ -14,16 +15,29 @@ const RARITY_RARE: &str = "rare";
 const RARITY_MYTHIC: &str = "mythic";
 const RARITY_SPECIAL: &str = "special";
 const RARITY_BONUS: &str = "bonus";
+const RARITY_TIMESHIFTED: &str = "timeshifted";
+const RARITY_MASTERPIECE: &str = "masterpiece";
+// The literal representation of legacy rarities used by older MTGJSON data.
+const RARITY_LEGACY_BASIC: &str = "basic";
+// The colours of the set symbol of the different rarities as hex strings.
+const SYMBOL_COLOUR_COMMON: &str = "#1A1718";
+const SYMBOL_COLOUR_UNCOMMON: &str = "#707883";
+const SYMBOL_COLOUR_RARE: &str = "#A58E4A";
+const SYMBOL_COLOUR_MYTHIC: &str = "#BF4427";
+const SYMBOL_COLOUR_TIMESHIFTED: &str = "#652978";
 
 /// The 'Rarity' of a Magic card.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Rarity {
+    #[default]
     Common,
     Uncommon,
     Rare,
     Mythic,
     Special,
     Bonus,
+    Timeshifted,
+    Masterpiece,
 }
 
 impl Rarity {
@@ -36,13 +50,60 @@ impl Rarity {
             Rarity::Mythic => 3,
             Rarity::Special => 4,
             Rarity::Bonus => 5,
+            Rarity::Timeshifted => 6,
+            Rarity::Masterpiece => 7,
+        }
+    }
+
+    /// Returns the colour the set symbol is filled with for this rarity as hex string.
+    /// Common symbols are black, uncommon symbols silver, rare symbols gold, mythic symbols
+    /// orange-bronze and timeshifted symbols purple. All other rarities default to the
+    /// black of common symbols.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::rarity::Rarity;
+    ///
+    /// assert_eq!(Rarity::Rare.symbol_colour(), "#A58E4A");
+    /// assert_eq!(Rarity::Bonus.symbol_colour(), Rarity::Common.symbol_colour());
+    /// ```
+    pub fn symbol_colour(&self) -> &'static str {
+        match self {
+            Rarity::Uncommon => SYMBOL_COLOUR_UNCOMMON,
+            Rarity::Rare => SYMBOL_COLOUR_RARE,
+            Rarity::Mythic => SYMBOL_COLOUR_MYTHIC,
+            Rarity::Timeshifted => SYMBOL_COLOUR_TIMESHIFTED,
+            Rarity::Common | Rarity::Special | Rarity::Bonus | Rarity::Masterpiece => {
+                SYMBOL_COLOUR_COMMON
+            }
         }
     }
-}
 
-impl Default for Rarity {
-    fn default() -> Self {
-        Rarity::Common
+    /// Converts a rarity as found in MTGJSON data into a `Rarity`. In contrast to the
+    /// conversion from a string this never fails, so that data with new rarities can still
+    /// be ingested. The legacy `basic` rarity of older MTGJSON data is mapped to
+    /// [`Common`](Rarity::Common) and all unknown rarities are mapped to
+    /// [`Special`](Rarity::Special).
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - the MTGJSON rarity to convert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use phyrexian_library::magic::rarity::Rarity;
+    ///
+    /// assert_eq!(Rarity::from_mtgjson("rare"), Rarity::Rare);
+    /// assert_eq!(Rarity::from_mtgjson("basic"), Rarity::Common);
+    /// assert_eq!(Rarity::from_mtgjson("legendary"), Rarity::Special);
+    /// ```
+    pub fn from_mtgjson(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            RARITY_LEGACY_BASIC => Rarity::Common,
+            _ => Rarity::try_from(value).unwrap_or(Rarity::Special),
+        }
     }
 }
 
@@ -73,6 +134,8 @@ impl From<&Rarity> for &str {
             Rarity::Mythic => RARITY_MYTHIC,
             Rarity::Special => RARITY_SPECIAL,
             Rarity::Bonus => RARITY_BONUS,
+            Rarity::Timeshifted => RARITY_TIMESHIFTED,
+            Rarity::Masterpiece => RARITY_MASTERPIECE,
         }
     }
 }
@@ -81,13 +144,15 @@ impl TryFrom<&str> for Rarity {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
+        match value.to_lowercase().as_str() {
             RARITY_COMMON => Ok(Rarity::Common),
             RARITY_UNCOMMON => Ok(Rarity::Uncommon),
             RARITY_RARE => Ok(Rarity::Rare),
             RARITY_MYTHIC => Ok(Rarity::Mythic),
             RARITY_SPECIAL => Ok(Rarity::Special),
             RARITY_BONUS => Ok(Rarity::Bonus),
+            RARITY_TIMESHIFTED => Ok(Rarity::Timeshifted),
+            RARITY_MASTERPIECE => Ok(Rarity::Masterpiece),
             _ => Err(format!("{} is not a valid rarity.", value)),
         }
     }
@@ -101,6 +166,14 @@ impl TryFrom<String> for Rarity {
     }
 }
 
+impl FromStr for Rarity {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Rarity::try_from(value)
+    }
+}
+
 impl fmt::Display for Rarity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self.into())
@@ -32,6 +32,8 @@ fn test_conversion_to_string() {
     assert_eq!(Into::<&str>::into(Rarity::Uncommon), RARITY_UNCOMMON);
     assert_eq!(Into::<&str>::into(Rarity::Rare), RARITY_RARE);
     assert_eq!(Into::<&str>::into(Rarity::Mythic), RARITY_MYTHIC);
+    assert_eq!(Into::<&str>::into(Rarity::Timeshifted), RARITY_TIMESHIFTED);
+    assert_eq!(Into::<&str>::into(Rarity::Masterpiece), RARITY_MASTERPIECE);
 }
 
 #[test]
@@ -45,4 +47,56 @@ fn test_conversion_from_string() {
     assert_eq!(TryInto::<Rarity>::try_into(RARITY_UNCOMMON.to_string()), Ok(Rarity::Uncommon));
     assert_eq!(TryInto::<Rarity>::try_into(RARITY_RARE.to_string()), Ok(Rarity::Rare));
     assert_eq!(TryInto::<Rarity>::try_into(RARITY_MYTHIC.to_string()), Ok(Rarity::Mythic));
+    assert_eq!(TryInto::<Rarity>::try_into(RARITY_TIMESHIFTED), Ok(Rarity::Timeshifted));
+    assert_eq!(TryInto::<Rarity>::try_into(RARITY_MASTERPIECE), Ok(Rarity::Masterpiece));
+    assert!(TryInto::<Rarity>::try_into("legendary").is_err());
+}
+
+#[test]
+/// Tests if the conversion from string to `Rarity` ignores case.
+fn test_conversion_from_string_ignoring_case() {
+    assert_eq!(TryInto::<Rarity>::try_into("rare"), Ok(Rarity::Rare));
+    assert_eq!(TryInto::<Rarity>::try_into("Rare"), Ok(Rarity::Rare));
+    assert_eq!(TryInto::<Rarity>::try_into("RARE"), Ok(Rarity::Rare));
+    assert_eq!(TryInto::<Rarity>::try_into("Mythic".to_string()), Ok(Rarity::Mythic));
+    assert_eq!(Rarity::from_mtgjson("Basic"), Rarity::Common);
+    assert_eq!(Rarity::Rare.to_string(), RARITY_RARE);
+    assert_eq!(
+        TryInto::<Rarity>::try_into("Legendary"),
+        Err("Legendary is not a valid rarity.".to_string())
+    );
+}
+
+#[test]
+/// Tests if the conversion from MTGJSON strings to `Rarity` works as expected.
+fn test_conversion_from_mtgjson() {
+    assert_eq!(Rarity::from_mtgjson(RARITY_COMMON), Rarity::Common);
+    assert_eq!(Rarity::from_mtgjson(RARITY_MYTHIC), Rarity::Mythic);
+    assert_eq!(Rarity::from_mtgjson(RARITY_SPECIAL), Rarity::Special);
+    assert_eq!(Rarity::from_mtgjson(RARITY_BONUS), Rarity::Bonus);
+    assert_eq!(Rarity::from_mtgjson(RARITY_TIMESHIFTED), Rarity::Timeshifted);
+    assert_eq!(Rarity::from_mtgjson(RARITY_MASTERPIECE), Rarity::Masterpiece);
+    assert_eq!(Rarity::from_mtgjson(RARITY_LEGACY_BASIC), Rarity::Common);
+    assert_eq!(Rarity::from_mtgjson("legendary"), Rarity::Special);
+    assert_eq!(Rarity::from_mtgjson(""), Rarity::Special);
+}
+
+#[test]
+/// Tests if `Rarity` can be parsed from a string.
+fn test_parse() {
+    assert_eq!("mythic".parse::<Rarity>(), Ok(Rarity::Mythic));
+    assert!("legendary".parse::<Rarity>().is_err());
+}
+
+#[test]
+/// Tests if the set symbol colours of `Rarity` are correct.
+fn test_symbol_colour() {
+    assert_eq!(Rarity::Common.symbol_colour(), "#1A1718");
+    assert_eq!(Rarity::Uncommon.symbol_colour(), "#707883");
+    assert_eq!(Rarity::Rare.symbol_colour(), "#A58E4A");
+    assert_eq!(Rarity::Mythic.symbol_colour(), "#BF4427");
+    assert_eq!(Rarity::Timeshifted.symbol_colour(), "#652978");
+    assert_eq!(Rarity::Special.symbol_colour(), "#1A1718");
+    assert_eq!(Rarity::Bonus.symbol_colour(), "#1A1718");
+    assert_eq!(Rarity::Masterpiece.symbol_colour(), "#1A1718");
 }
@@ -0,0 +1,178 @@
+//! The 'type_line' module provides structures for the classification of card types.
+
+extern crate serde;
+
+use serde::{Serialize, Deserialize};
+use std::convert::TryFrom;
+use std::fmt;
+
+// The separators between the types and the subtypes of a type line.
+const TYPE_LINE_SEPARATOR: char = '—';
+const TYPE_LINE_SEPARATOR_PLAIN: char = '-';
+// The literal representation of all the supertypes.
+const SUPERTYPES: [&str; 7] = ["Basic", "Elite", "Host", "Legendary", "Ongoing", "Snow", "World"];
+// The literal representation of the card types queried by the convenience methods.
+const TYPE_ARTIFACT: &str = "Artifact";
+const TYPE_CREATURE: &str = "Creature";
+const TYPE_ENCHANTMENT: &str = "Enchantment";
+const TYPE_INSTANT: &str = "Instant";
+const TYPE_LAND: &str = "Land";
+const TYPE_PLANESWALKER: &str = "Planeswalker";
+const TYPE_SORCERY: &str = "Sorcery";
+const SUPERTYPE_BASIC: &str = "Basic";
+const SUPERTYPE_LEGENDARY: &str = "Legendary";
+
+/// The 'TypeLine' of a card split into its supertypes, card types and subtypes.
+/// All queries ignore case.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Getters, Serialize, Deserialize)]
+pub struct TypeLine {
+    #[getset(get = "pub")]
+    /// The supertypes like `Legendary` or `Basic`.
+    supertypes: Vec<String>,
+
+    #[getset(get = "pub")]
+    /// The card types like `Creature` or `Land`.
+    types: Vec<String>,
+
+    #[getset(get = "pub")]
+    /// The subtypes like `Elf` or `Forest`.
+    subtypes: Vec<String>,
+}
+
+impl TypeLine {
+    /// Checks if the type line contains the specified supertype.
+    ///
+    /// # Parameters
+    ///
+    /// * `supertype` - the supertype to check for
+    pub fn has_supertype(&self, supertype: &str) -> bool {
+        contains_ignoring_case(&self.supertypes, supertype)
+    }
+
+    /// Checks if the type line contains the specified card type.
+    ///
+    /// # Parameters
+    ///
+    /// * `card_type` - the card type to check for
+    pub fn has_type(&self, card_type: &str) -> bool {
+        contains_ignoring_case(&self.types, card_type)
+    }
+
+    /// Checks if the type line contains the specified subtype.
+    ///
+    /// # Parameters
+    ///
+    /// * `subtype` - the subtype to check for
+    pub fn has_subtype(&self, subtype: &str) -> bool {
+        contains_ignoring_case(&self.subtypes, subtype)
+    }
+
+    /// Checks if the type line describes an artifact.
+    pub fn is_artifact(&self) -> bool {
+        self.has_type(TYPE_ARTIFACT)
+    }
+
+    /// Checks if the type line describes a creature.
+    pub fn is_creature(&self) -> bool {
+        self.has_type(TYPE_CREATURE)
+    }
+
+    /// Checks if the type line describes an enchantment.
+    pub fn is_enchantment(&self) -> bool {
+        self.has_type(TYPE_ENCHANTMENT)
+    }
+
+    /// Checks if the type line describes an instant.
+    pub fn is_instant(&self) -> bool {
+        self.has_type(TYPE_INSTANT)
+    }
+
+    /// Checks if the type line describes a land.
+    pub fn is_land(&self) -> bool {
+        self.has_type(TYPE_LAND)
+    }
+
+    /// Checks if the type line describes a planeswalker.
+    pub fn is_planeswalker(&self) -> bool {
+        self.has_type(TYPE_PLANESWALKER)
+    }
+
+    /// Checks if the type line describes a sorcery.
+    pub fn is_sorcery(&self) -> bool {
+        self.has_type(TYPE_SORCERY)
+    }
+
+    /// Checks if the type line contains the `Basic` supertype.
+    pub fn is_basic(&self) -> bool {
+        self.has_supertype(SUPERTYPE_BASIC)
+    }
+
+    /// Checks if the type line contains the `Legendary` supertype.
+    pub fn is_legendary(&self) -> bool {
+        self.has_supertype(SUPERTYPE_LEGENDARY)
+    }
+}
+
+/// Checks if the values contain the specified value ignoring case.
+///
+/// # Parameters
+///
+/// * `values` - the values to search
+/// * `value` - the value to search for
+fn contains_ignoring_case(values: &[String], value: &str) -> bool {
+    values.iter().any(|v| v.eq_ignore_ascii_case(value))
+}
+
+/// Splits the specified part of a type line into its whitespace separated words.
+///
+/// # Parameters
+///
+/// * `value` - the part of the type line to split
+fn split_words(value: &str) -> Vec<String> {
+    value.split_whitespace().map(str::to_string).collect()
+}
+
+impl TryFrom<&str> for TypeLine {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (types, subtypes) = value
+            .split_once(TYPE_LINE_SEPARATOR)
+            .or_else(|| value.split_once(TYPE_LINE_SEPARATOR_PLAIN))
+            .unwrap_or((value, ""));
+        let (supertypes, types): (Vec<String>, Vec<String>) = split_words(types)
+            .into_iter()
+            .partition(|word| SUPERTYPES.iter().any(|s| s.eq_ignore_ascii_case(word)));
+        if types.is_empty() {
+            return Err(format!("{} is not a valid type line.", value));
+        }
+        Ok(TypeLine {
+            supertypes,
+            types,
+            subtypes: split_words(subtypes),
+        })
+    }
+}
+
+impl TryFrom<String> for TypeLine {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        TypeLine::try_from(value.as_str())
+    }
+}
+
+impl fmt::Display for TypeLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let types: Vec<&str> =
+            self.supertypes.iter().chain(&self.types).map(String::as_str).collect();
+        f.write_str(&types.join(" "))?;
+        if !self.subtypes.is_empty() {
+            write!(f, " {} {}", TYPE_LINE_SEPARATOR, self.subtypes.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
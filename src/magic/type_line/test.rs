@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+/// Tests if parsing the type line of a legendary creature works as expected.
+fn test_legendary_creature() {
+    let type_line = TypeLine::try_from("Legendary Creature — Elf Warrior").unwrap();
+    assert_eq!(type_line.supertypes(), &vec!("Legendary".to_string()));
+    assert_eq!(type_line.types(), &vec!("Creature".to_string()));
+    assert_eq!(type_line.subtypes(), &vec!("Elf".to_string(), "Warrior".to_string()));
+    assert!(type_line.is_legendary());
+    assert!(type_line.is_creature());
+    assert!(!type_line.is_land());
+    assert!(type_line.has_subtype("elf"));
+    assert!(!type_line.has_subtype("Goblin"));
+    assert_eq!(type_line.to_string(), "Legendary Creature — Elf Warrior");
+}
+
+#[test]
+/// Tests if parsing the type line of a basic land works as expected.
+fn test_basic_land() {
+    let type_line = TypeLine::try_from("Basic Snow Land - Forest").unwrap();
+    assert_eq!(type_line.supertypes(), &vec!("Basic".to_string(), "Snow".to_string()));
+    assert!(type_line.is_basic());
+    assert!(type_line.is_land());
+    assert!(type_line.has_supertype("snow"));
+    assert!(type_line.has_subtype("Forest"));
+    assert_eq!(type_line.to_string(), "Basic Snow Land — Forest");
+    // Hyphens within subtypes are kept.
+    let type_line = TypeLine::try_from("Artifact Creature - Assembly-Worker").unwrap();
+    assert!(type_line.is_artifact());
+    assert!(type_line.has_subtype("Assembly-Worker"));
+}
+
+#[test]
+/// Tests if parsing a type line without subtypes works as expected.
+fn test_no_subtypes() {
+    let type_line = TypeLine::try_from("Instant".to_string()).unwrap();
+    assert!(type_line.supertypes().is_empty());
+    assert!(type_line.is_instant());
+    assert!(type_line.subtypes().is_empty());
+    assert_eq!(type_line.to_string(), "Instant");
+    assert!(TypeLine::try_from("").is_err());
+    assert!(TypeLine::try_from("Legendary — Elf").is_err());
+}
@@ -1,2 +1,110 @@
+use crate::application::error::PhyrexianError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
 pub mod download;
 pub mod image_tools;
+
+/// Compares two strings naturally, so that runs of digits are compared by their numeric
+/// value and runs of all other characters lexically. This orders collector numbers the way
+/// they are printed, e.g. `2` < `10` < `12` < `12a` < `100`. Runs of digits precede runs of
+/// other characters. Strings that only differ in leading zeros are ordered lexically.
+///
+/// # Arguments
+///
+/// * `a` - The first string to compare.
+/// * `b` - The second string to compare.
+///
+/// # Examples
+/// ```
+/// use phyrexian_library::utility::natural_cmp;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(natural_cmp("2", "10"), Ordering::Less);
+/// assert_eq!(natural_cmp("12a", "12"), Ordering::Greater);
+/// assert_eq!(natural_cmp("12a", "12a"), Ordering::Equal);
+/// ```
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut rest_a, mut rest_b) = (a, b);
+    loop {
+        match (rest_a.is_empty(), rest_b.is_empty()) {
+            (true, true) => return a.cmp(b),
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        }
+        let (chunk_a, remainder_a) = split_natural_chunk(rest_a);
+        let (chunk_b, remainder_b) = split_natural_chunk(rest_b);
+        let numeric_a = chunk_a.starts_with(|c: char| c.is_ascii_digit());
+        let numeric_b = chunk_b.starts_with(|c: char| c.is_ascii_digit());
+        let ordering = match (numeric_a, numeric_b) {
+            (true, true) => {
+                let digits_a = chunk_a.trim_start_matches('0');
+                let digits_b = chunk_b.trim_start_matches('0');
+                digits_a
+                    .len()
+                    .cmp(&digits_b.len())
+                    .then_with(|| digits_a.cmp(digits_b))
+            }
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => chunk_a.cmp(chunk_b),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        rest_a = remainder_a;
+        rest_b = remainder_b;
+    }
+}
+
+/// Splits the leading run of either digits or other characters from the string.
+///
+/// # Arguments
+///
+/// * `value` - The string to split.
+fn split_natural_chunk(value: &str) -> (&str, &str) {
+    let numeric = value.starts_with(|c: char| c.is_ascii_digit());
+    let end = value
+        .find(|c: char| c.is_ascii_digit() != numeric)
+        .unwrap_or(value.len());
+    value.split_at(end)
+}
+
+/// Writes the value to the specified file as human readable JSON. Missing parent
+/// directories are created.
+///
+/// # Arguments
+///
+/// * `value` - The value to write.
+/// * `path` - The path of the file to write to.
+pub(crate) fn save_json<T: Serialize, P: AsRef<Path>>(
+    value: &T,
+    path: P,
+) -> Result<(), PhyrexianError> {
+    if let Some(parent_path) = path.as_ref().parent() {
+        std::fs::create_dir_all(parent_path)?;
+    }
+    let writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(writer, value)?;
+    Ok(())
+}
+
+/// Reads a value from the specified JSON file as written by [`save_json`](save_json).
+///
+/// # Arguments
+///
+/// * `path` - The path of the file to read from.
+pub(crate) fn load_json<T: DeserializeOwned, P: AsRef<Path>>(
+    path: P,
+) -> Result<T, PhyrexianError> {
+    let reader = BufReader::new(File::open(path)?);
+    Ok(serde_json::from_reader(reader)?)
+}
+
+#[cfg(test)]
+mod test;
@@ -1,33 +1,188 @@
 //! The `download` module contains a rudimentary download manager for
 //! asynchronous download of files via HTTP or HTTPS.
 
+extern crate flate2;
 extern crate parking_lot;
 extern crate rayon;
 extern crate reqwest;
 
-use parking_lot::Mutex;
+use flate2::read::GzDecoder;
+use parking_lot::{Condvar, Mutex};
 use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
-use reqwest::header::CONTENT_LENGTH;
+use reqwest::header::{
+    HeaderMap, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH, ETAG, IF_NONE_MATCH,
+};
+use reqwest::StatusCode;
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::{fs, fs::OpenOptions};
-use std::{io, io::Read, io::Write};
+use std::time::{Duration, Instant, SystemTime};
+use std::{fs, fs::File, fs::OpenOptions};
+use std::{io, io::Read, io::Seek, io::SeekFrom, io::Write};
+
+#[cfg(feature = "async")]
+pub mod asynchronous;
 
 /// The number of threads per DownloadManager instance.
 /// This corresponds to the maximum number of simultanious downloads a manager can perform.
 const DOWNLOAD_MANAGER_NUMBER_OF_THREADS: usize = 4;
 
 /// The time interval over which the download speed is averaged.
-const DOWNLOAD_SPEED_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+const DOWNLOAD_SPEED_INTERVAL: Duration = Duration::from_millis(200);
+
+/// The longest time a throttled download pauses before checking whether it was cancelled.
+const CANCELLATION_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The default size of the buffer data is read into before it is written to a file.
+const DEFAULT_BUFFER_SIZE: usize = 128 * 1024;
+
+/// The extension appended to the name of an output file to obtain the name of
+/// the temporary file a download is written to.
+const TEMPORARY_FILE_EXTENSION: &str = ".part";
+
+/// The extension of gzip-compressed files.
+const GZIP_FILE_EXTENSION: &str = ".gz";
+
+/// A source of time used to measure the progress of downloads.
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    /// Returns the current point in time.
+    fn now(&self) -> Instant;
+
+    /// Returns the current wall clock time, which is used to record when downloads start
+    /// and finish. The system time is used by default.
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A [`Clock`] reading the monotonic system time.
+///
+/// [`Clock`]: ./trait.Clock.html
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A [`Clock`] that only advances when told to, allowing deterministic tests
+/// of time dependent download behaviour.
+///
+/// # Examples
+/// ```
+/// use phyrexian_library::utility::download::{Clock, MockClock};
+/// use std::time::Duration;
+///
+/// let clock = MockClock::new();
+/// let start = clock.now();
+/// let start_time = clock.system_time();
+/// clock.advance(Duration::from_secs(2));
+/// assert_eq!(clock.now() - start, Duration::from_secs(2));
+/// assert_eq!(clock.system_time().duration_since(start_time).unwrap(), Duration::from_secs(2));
+/// ```
+///
+/// [`Clock`]: ./trait.Clock.html
+#[derive(Debug)]
+pub struct MockClock {
+    start: Instant,
+    start_time: SystemTime,
+    elapsed: Mutex<Duration>,
+}
+
+impl MockClock {
+    /// Creates a new `MockClock` frozen at the current point in time.
+    pub fn new() -> Self {
+        MockClock {
+            start: Instant::now(),
+            start_time: SystemTime::now(),
+            elapsed: Mutex::new(Duration::from_secs(0)),
+        }
+    }
+
+    /// Advances the clock by the specified amount of time.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The amount of time to advance the clock by.
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock() += duration;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        MockClock::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.start + *self.elapsed.lock()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.start_time + *self.elapsed.lock()
+    }
+}
 
 /// A manager for asynchronous download of files via HTTP and HTTPS.
+///
+/// Dropping the manager cancels all downloads that have not finished yet and blocks
+/// until its workers stopped writing to their output files.
 #[derive(Debug)]
 pub struct DownloadManager {
     pool: ThreadPool,
     downloads: HashMap<Arc<PathBuf>, Arc<Mutex<Download>>>,
+    configuration: DownloadConfiguration,
+    /// The number of downloads submitted to the pool that have not finished yet, which is
+    /// signalled whenever it changes.
+    active_workers: Arc<(Mutex<usize>, Condvar)>,
+}
+
+/// The settings a [`DownloadManager`] applies to the downloads it starts.
+///
+/// [`DownloadManager`]: ./struct.DownloadManager.html
+#[derive(Debug, Clone)]
+struct DownloadConfiguration {
+    /// The clock to measure the progress of downloads with.
+    clock: Arc<dyn Clock>,
+    /// Whether data is written to a temporary file before replacing the output file.
+    temporary_file: bool,
+    /// The client to send requests with, which is shared by all downloads, so that they
+    /// share its connection pool. A new default client is used per download if unset.
+    client: Option<Arc<reqwest::Client>>,
+    /// The client to send requests of downloads that decompress their data with. It does
+    /// not decompress responses on its own. A new client is used per download if unset.
+    decompressing_client: Option<Arc<reqwest::Client>>,
+    /// The size of the buffer data is read into before it is written to a file.
+    buffer_size: usize,
+    /// The limit of the download rate shared by all downloads if any.
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// Whether the downloads have been cancelled.
+    cancelled: Arc<AtomicBool>,
+    /// Whether gzip-compressed responses are decompressed before they are written.
+    decompress: bool,
+}
+
+impl Default for DownloadConfiguration {
+    fn default() -> Self {
+        DownloadConfiguration {
+            clock: Arc::new(SystemClock),
+            temporary_file: true,
+            client: None,
+            decompressing_client: None,
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            rate_limiter: None,
+            cancelled: Arc::new(AtomicBool::new(false)),
+            decompress: false,
+        }
+    }
 }
 
 impl DownloadManager {
@@ -47,14 +202,172 @@ impl DownloadManager {
     /// # Errors
     /// Returns an error if creation of the underlying thread pool failed.
     pub fn new() -> Result<DownloadManager, ThreadPoolBuildError> {
+        // If the shared clients cannot be created, each download creates its own client
+        // and reports the failure instead.
+        let configuration = DownloadConfiguration {
+            client: reqwest::Client::builder().build().ok().map(Arc::new),
+            decompressing_client: reqwest::Client::builder().gzip(false).build().ok().map(Arc::new),
+            ..DownloadConfiguration::default()
+        };
         Ok(DownloadManager {
             pool: ThreadPoolBuilder::new()
                 .num_threads(DOWNLOAD_MANAGER_NUMBER_OF_THREADS)
                 .build()?,
             downloads: HashMap::new(),
+            configuration,
+            active_workers: Arc::new((Mutex::new(0), Condvar::new())),
         })
     }
 
+    /// Replaces the [`Clock`] used to measure the progress of downloads started
+    /// afterwards. The [`SystemClock`] is used by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - The clock to read the time from.
+    ///
+    /// # Examples
+    /// ```
+    /// use phyrexian_library::utility::download::{DownloadManager, MockClock};
+    /// use std::sync::Arc;
+    ///
+    /// let download_manager = DownloadManager::new().unwrap().with_clock(Arc::new(MockClock::new()));
+    /// ```
+    ///
+    /// [`Clock`]: ./trait.Clock.html
+    /// [`SystemClock`]: ./struct.SystemClock.html
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.configuration.clock = clock;
+        self
+    }
+
+    /// Sets whether downloads started afterwards write their data to a temporary
+    /// file first. The temporary file is named like the output file with an
+    /// additional `.part` extension and replaces the output file only after the
+    /// download completed successfully, so that a failed download never corrupts
+    /// an already existing file. Temporary files are used by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `temporary_file` - `true` if data should be written to a temporary file first.
+    ///
+    /// # Examples
+    /// ```
+    /// use phyrexian_library::utility::download::DownloadManager;
+    ///
+    /// let download_manager = DownloadManager::new().unwrap().with_temporary_file(false);
+    /// ```
+    pub fn with_temporary_file(mut self, temporary_file: bool) -> Self {
+        self.configuration.temporary_file = temporary_file;
+        self
+    }
+
+    /// Sets the policy how downloads started afterwards handle redirects. By default up to
+    /// 10 redirects are followed.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy to handle redirects with.
+    ///
+    /// # Examples
+    /// ```
+    /// use phyrexian_library::utility::download::DownloadManager;
+    /// use reqwest::RedirectPolicy;
+    ///
+    /// let download_manager = DownloadManager::new()
+    ///     .unwrap()
+    ///     .with_redirect_policy(RedirectPolicy::none())
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP clients applying the policy could not be created.
+    pub fn with_redirect_policy(
+        mut self,
+        policy: reqwest::RedirectPolicy,
+    ) -> Result<Self, DownloadError> {
+        // Policies cannot be cloned, so both clients delegate to the same policy.
+        let policy = Arc::new(policy);
+        let delegate = |policy: &Arc<reqwest::RedirectPolicy>| {
+            let policy = Arc::clone(policy);
+            reqwest::RedirectPolicy::custom(move |attempt| policy.redirect(attempt))
+        };
+        let client = reqwest::Client::builder().redirect(delegate(&policy)).build()?;
+        let decompressing_client = reqwest::Client::builder()
+            .gzip(false)
+            .redirect(delegate(&policy))
+            .build()?;
+        self.configuration.client = Some(Arc::new(client));
+        self.configuration.decompressing_client = Some(Arc::new(decompressing_client));
+        Ok(self)
+    }
+
+    /// Sets the size in bytes of the buffer downloads started afterwards read data into
+    /// before writing it to a file. Small buffers save memory when downloading many small
+    /// files, while large buffers may improve the throughput for large files. The buffer
+    /// size is 128 KiB by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer_size` - The size of the buffer in bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use phyrexian_library::utility::download::DownloadManager;
+    ///
+    /// let download_manager = DownloadManager::new().unwrap().with_buffer_size(1024).unwrap();
+    /// assert!(DownloadManager::new().unwrap().with_buffer_size(0).is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the buffer size is zero.
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Result<Self, DownloadError> {
+        if buffer_size == 0 {
+            return Err(DownloadError::from(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The buffer size of a download must not be zero.",
+            )));
+        }
+        self.configuration.buffer_size = buffer_size;
+        Ok(self)
+    }
+
+    /// Limits the combined rate at which all downloads started afterwards write data.
+    /// Downloads pause whenever the average rate over the current
+    /// [`DOWNLOAD_SPEED_INTERVAL`] exceeds the limit. Downloads are not limited by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_bytes_per_second` - The maximum combined download rate in byte/sec.
+    ///
+    /// # Examples
+    /// ```
+    /// use phyrexian_library::utility::download::DownloadManager;
+    ///
+    /// let download_manager = DownloadManager::new()
+    ///     .unwrap()
+    ///     .with_max_bytes_per_second(1024 * 1024)
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the limit is zero.
+    ///
+    /// [`DOWNLOAD_SPEED_INTERVAL`]: ./constant.DOWNLOAD_SPEED_INTERVAL.html
+    pub fn with_max_bytes_per_second(
+        mut self,
+        max_bytes_per_second: u64,
+    ) -> Result<Self, DownloadError> {
+        if max_bytes_per_second == 0 {
+            return Err(DownloadError::from(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The download rate limit must not be zero.",
+            )));
+        }
+        self.configuration.rate_limiter = Some(Arc::new(RateLimiter::new(max_bytes_per_second)));
+        Ok(self)
+    }
+
     /// Returns a [`DownloadProxy`] of the download for the specified file if any.
     /// The object allows interaction with the underlying [`Download`].
     ///
@@ -71,7 +384,7 @@ impl DownloadManager {
         self.downloads
             .get(&Arc::new(path_to_output_file.as_ref().to_path_buf()))
             .map(|val| DownloadProxy {
-                download: Arc::clone(&val),
+                download: Arc::clone(val),
             })
     }
 
@@ -86,12 +399,231 @@ impl DownloadManager {
         U: reqwest::IntoUrl + Send + 'static,
         P: AsRef<Path>,
     {
-        let download: Arc<Mutex<Download>> = Arc::new(Mutex::new(Download::pending()));
+        self.download_with_callback(link, output, |_| {});
+    }
+
+    /// Downloads a file via HTTP or HTTPS just like [`download`], but derives the path of the
+    /// output file from the URL. The path is obtained by resolving the placeholders of the
+    /// template relative to the specified directory. The supported placeholders are:
+    ///
+    /// * `{host}` - The host of the URL.
+    /// * `{filename}` - The last segment of the URL path.
+    /// * `{stem}` - The last segment of the URL path without its extension.
+    /// * `{ext}` - The extension of the last segment of the URL path.
+    ///
+    /// Returns the path of the output file, which identifies the download.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` - A URL to a file, which should be downloaded.
+    /// * `directory` - The directory the resolved template is relative to.
+    /// * `template` - The template of the path of the output file.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use phyrexian_library::utility::download::DownloadManager;
+    ///
+    /// let mut download_manager = DownloadManager::new().unwrap();
+    /// let link = "https://mtgjson.com/api/v5/Meta.json";
+    /// let output = download_manager
+    ///     .download_templated(link, "downloads", "{host}/{filename}")
+    ///     .unwrap();
+    /// assert_eq!(output, std::path::Path::new("downloads/mtgjson.com/Meta.json"));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error without starting the download if the link is not a valid URL, the
+    /// template contains an unknown or unclosed placeholder or a placeholder cannot be
+    /// resolved from the URL.
+    ///
+    /// [`download`]: #method.download
+    pub fn download_templated<U, P>(
+        &mut self,
+        link: U,
+        directory: P,
+        template: &str,
+    ) -> Result<PathBuf, DownloadError>
+    where
+        U: reqwest::IntoUrl,
+        P: AsRef<Path>,
+    {
+        let url = link.into_url()?;
+        let output = directory.as_ref().join(resolve_template(&url, template)?);
+        self.download(url, &output);
+        Ok(output)
+    }
+
+    /// Downloads a file via HTTP or HTTPS just like [`download`] unless the output file
+    /// already exists and is not empty. In that case the download is immediately marked as
+    /// successful without any network access. The content of the existing file is not
+    /// validated, so an incomplete file from a previous run is kept as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` - A URL to a file, which should be downloaded.
+    /// * `output` - A path specifying the file to which the downloaded data is written.
+    ///
+    /// [`download`]: #method.download
+    pub fn download_if_missing<U, P>(&mut self, link: U, output: P)
+    where
+        U: reqwest::IntoUrl + Send + 'static,
+        P: AsRef<Path>,
+    {
+        match fs::metadata(output.as_ref()) {
+            Ok(metadata) if metadata.is_file() && metadata.len() > 0 => {
+                let clock = self.configuration.clock.as_ref();
+                let mut download = Download::pending();
+                download.total_size = Some(metadata.len());
+                download.downloaded_size = metadata.len();
+                download.log_event("skipped: the file already exists", clock.now());
+                download.transition(DownloadStatus::Successful, clock);
+                self.downloads.insert(
+                    Arc::new(output.as_ref().to_path_buf()),
+                    Arc::new(Mutex::new(download)),
+                );
+            }
+            _ => self.download(link, output),
+        }
+    }
+
+    /// Downloads a file via HTTP or HTTPS just like [`download`] and invokes the callback
+    /// once the download has finished. The callback is invoked from the worker thread
+    /// performing the download and receives the path of the output file on success or
+    /// the cause of the failure otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` - A URL to a file, which should be downloaded.
+    /// * `output` - A path specifying the file to which the downloaded data is written.
+    /// * `on_done` - The callback to invoke once the download has finished.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use phyrexian_library::utility::download::DownloadManager;
+    ///
+    /// let mut download_manager = DownloadManager::new().unwrap();
+    /// download_manager.download_with_callback(
+    ///     "https://mtgjson.com/api/v5/Meta.json",
+    ///     "Meta.json",
+    ///     |result| match result {
+    ///         Ok(path) => println!("Downloaded {:?}.", path),
+    ///         Err(err) => eprintln!("The download failed: {}", err),
+    ///     },
+    /// );
+    /// ```
+    ///
+    /// [`download`]: #method.download
+    pub fn download_with_callback<U, P, F>(&mut self, link: U, output: P, on_done: F)
+    where
+        U: reqwest::IntoUrl + Send + 'static,
+        P: AsRef<Path>,
+        F: FnOnce(Result<PathBuf, Arc<DownloadError>>) + Send + 'static,
+    {
+        let configuration = self.configuration.clone();
+        self.spawn_download(link, output, Download::pending(), configuration, on_done);
+    }
+
+    /// Downloads a file via HTTP or HTTPS just like [`download`], but decompresses the
+    /// response if it is gzip-compressed, which is the case if its `Content-Encoding` is
+    /// `gzip` or the URL ends in `.gz`. The decompressed data is written to the output file,
+    /// while the progress of the download tracks the compressed data received.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` - A URL to a file, which should be downloaded.
+    /// * `output` - A path specifying the file to which the decompressed data is written.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use phyrexian_library::utility::download::DownloadManager;
+    ///
+    /// let mut download_manager = DownloadManager::new().unwrap();
+    /// download_manager.download_decompressed(
+    ///     "https://mtgjson.com/api/v5/AllPrintings.json.gz",
+    ///     "AllPrintings.json",
+    /// );
+    /// ```
+    ///
+    /// [`download`]: #method.download
+    pub fn download_decompressed<U, P>(&mut self, link: U, output: P)
+    where
+        U: reqwest::IntoUrl + Send + 'static,
+        P: AsRef<Path>,
+    {
+        let mut configuration = self.configuration.clone();
+        configuration.decompress = true;
+        // The regular client decompresses responses on its own, so a client that does not
+        // is created for the download if no shared one is available.
+        configuration.client = configuration.decompressing_client.take();
+        self.spawn_download(link, output, Download::pending(), configuration, |_| {});
+    }
+
+    /// Downloads a file via HTTP or HTTPS just like [`download`], but only if it changed
+    /// since the specified ETag was issued. If the server responds with `304 Not Modified`
+    /// the download is marked as successful without touching the output file. The ETag
+    /// the server currently associates with the file is available via
+    /// [`DownloadProxy::etag`] once the download has finished.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` - A URL to a file, which should be downloaded.
+    /// * `output` - A path specifying the file to which the downloaded data is written.
+    /// * `etag` - The ETag of the previously downloaded version of the file if any.
+    ///
+    /// [`download`]: #method.download
+    /// [`DownloadProxy::etag`]: ./struct.DownloadProxy.html#method.etag
+    pub fn download_conditional<U, P>(&mut self, link: U, output: P, etag: Option<String>)
+    where
+        U: reqwest::IntoUrl + Send + 'static,
+        P: AsRef<Path>,
+    {
+        let mut download = Download::pending();
+        download.etag = etag;
+        let configuration = self.configuration.clone();
+        self.spawn_download(link, output, download, configuration, |_| {});
+    }
+
+    /// Registers the download and performs it on the thread pool of the manager.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` - A URL to a file, which should be downloaded.
+    /// * `output` - A path specifying the file to which the downloaded data is written.
+    /// * `download` - The pending download to perform.
+    /// * `configuration` - The settings to apply to the download.
+    /// * `on_done` - The callback to invoke once the download has finished.
+    fn spawn_download<U, P, F>(
+        &mut self,
+        link: U,
+        output: P,
+        download: Download,
+        configuration: DownloadConfiguration,
+        on_done: F,
+    ) where
+        U: reqwest::IntoUrl + Send + 'static,
+        P: AsRef<Path>,
+        F: FnOnce(Result<PathBuf, Arc<DownloadError>>) + Send + 'static,
+    {
+        let download: Arc<Mutex<Download>> = Arc::new(Mutex::new(download));
         let output_path: Arc<PathBuf> = Arc::new(output.as_ref().to_path_buf());
         self.downloads
             .insert(Arc::clone(&output_path), Arc::clone(&download));
+        let worker = WorkerGuard::new(Arc::clone(&self.active_workers));
         self.pool.spawn(move || {
-            download_to_file(link, output_path, download);
+            download_to_file(
+                link,
+                Arc::clone(&output_path),
+                Arc::clone(&download),
+                configuration,
+            );
+            // The download is finished before the callback is invoked, so that dropping the
+            // manager from within the callback does not wait for the callback itself.
+            drop(worker);
+            let error = download.lock().status.get_error();
+            on_done(match error {
+                Some(err) => Err(err),
+                None => Ok(output_path.as_ref().clone()),
+            });
         });
     }
 
@@ -113,7 +645,7 @@ impl DownloadManager {
         for val in self.downloads.values() {
             if let DownloadStatus::Failed(_) = val.lock().status {
                 failed.push(DownloadProxy {
-                    download: Arc::clone(&val),
+                    download: Arc::clone(val),
                 });
             }
         }
@@ -122,11 +654,42 @@ impl DownloadManager {
         failed
     }
 
+    /// Cancels all downloads that have not finished yet and removes all downloads from the
+    /// manager regardless of their state. Returns a list of the removed downloads. Cancelled
+    /// downloads fail, but in contrast to [`shutdown`] this does not wait for them to stop.
+    /// Downloads started afterwards are not affected by the cancellation.
+    ///
+    /// [`shutdown`]: #method.shutdown
+    pub fn clear(&mut self) -> Vec<DownloadProxy> {
+        self.configuration.cancelled.store(true, Ordering::SeqCst);
+        self.configuration.cancelled = Arc::new(AtomicBool::new(false));
+        self.downloads
+            .drain()
+            .map(|(_, download)| DownloadProxy { download })
+            .collect()
+    }
+
     /// Returns the number of downloads in this manager.
     pub fn size(&self) -> usize {
         self.downloads.len()
     }
 
+    /// Returns the combined download speed in byte/sec of all running downloads.
+    pub fn total_speed(&self) -> f64 {
+        self.downloads
+            .values()
+            .filter_map(|download| download.lock().get_download_speed())
+            .sum()
+    }
+
+    /// Returns the combined number of bytes downloaded by all downloads in this manager.
+    pub fn total_downloaded(&self) -> u64 {
+        self.downloads
+            .values()
+            .map(|download| download.lock().get_downloaded_size())
+            .sum()
+    }
+
     /*pub fn print_all(&self) {
         let mut success = 0;
         let mut pending = 0;
@@ -144,6 +707,68 @@ impl DownloadManager {
         }
         println!("Success: {}\nRunning: {}\nPending: {}\nFailed: {}", success, running, pending, failures);
     }*/
+
+    /// Cancels all downloads that have not finished yet and waits until the workers
+    /// stopped writing to their output files. This is equivalent to dropping the manager.
+    /// Cancelled downloads fail and, if temporary files are used, leave the output
+    /// files untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use phyrexian_library::utility::download::DownloadManager;
+    ///
+    /// let download_manager = DownloadManager::new().unwrap();
+    /// download_manager.shutdown();
+    /// ```
+    pub fn shutdown(mut self) {
+        self.cancel_and_wait();
+    }
+
+    /// Cancels all downloads that have not finished yet and blocks until no worker
+    /// is active anymore.
+    fn cancel_and_wait(&mut self) {
+        self.configuration.cancelled.store(true, Ordering::SeqCst);
+        let (active_workers, finished) = &*self.active_workers;
+        let mut active_workers = active_workers.lock();
+        while *active_workers > 0 {
+            finished.wait(&mut active_workers);
+        }
+    }
+}
+
+impl Drop for DownloadManager {
+    fn drop(&mut self) {
+        // Nothing is left to wait for if the manager has been shut down already.
+        self.cancel_and_wait();
+    }
+}
+
+/// Tracks a download submitted to the pool of a [`DownloadManager`] until it is dropped.
+///
+/// [`DownloadManager`]: ./struct.DownloadManager.html
+#[derive(Debug)]
+struct WorkerGuard {
+    active_workers: Arc<(Mutex<usize>, Condvar)>,
+}
+
+impl WorkerGuard {
+    /// Registers a new active worker.
+    ///
+    /// # Arguments
+    ///
+    /// * `active_workers` - The number of active workers to register at.
+    fn new(active_workers: Arc<(Mutex<usize>, Condvar)>) -> Self {
+        *active_workers.0.lock() += 1;
+        WorkerGuard { active_workers }
+    }
+}
+
+impl Drop for WorkerGuard {
+    fn drop(&mut self) {
+        let (active_workers, finished) = &*self.active_workers;
+        *active_workers.lock() -= 1;
+        finished.notify_all();
+    }
 }
 
 /// An enum containing all the potential errors that may occur during a download.
@@ -196,40 +821,28 @@ impl DownloadStatus {
     ///
     /// [`Pending`]: #variant.Pending
     fn is_pending(&self) -> bool {
-        match self {
-            DownloadStatus::Pending => true,
-            _ => false,
-        }
+        matches!(self, DownloadStatus::Pending)
     }
 
     /// Returns `true` if the status is a [`Running`] value.
     ///
     /// [`Running`]: #variant.Running
     fn is_running(&self) -> bool {
-        match self {
-            DownloadStatus::Running => true,
-            _ => false,
-        }
+        matches!(self, DownloadStatus::Running)
     }
 
     /// Returns `true` if the status is a [`Successful`] value.
     ///
     /// [`Successful`]: #variant.Successful
     fn is_successful(&self) -> bool {
-        match self {
-            DownloadStatus::Successful => true,
-            _ => false,
-        }
+        matches!(self, DownloadStatus::Successful)
     }
 
     /// Returns `true` if the status is a [`Failed`] value.
     ///
     /// [`Failed`]: #variant.Failed
     fn is_failed(&self) -> bool {
-        match self {
-            DownloadStatus::Failed(_) => true,
-            _ => false,
-        }
+        matches!(self, DownloadStatus::Failed(_))
     }
 
     /// Returns the error cause of a failed [`Download`] if applicable.
@@ -274,6 +887,10 @@ pub struct Download {
     downloaded_size: u64,
     total_size: Option<u64>,
     speed: f64,
+    events: Vec<(Instant, String)>,
+    etag: Option<String>,
+    started_at: Option<SystemTime>,
+    finished_at: Option<SystemTime>,
 }
 
 impl Download {
@@ -284,6 +901,61 @@ impl Download {
             downloaded_size: 0,
             total_size: None,
             speed: 0f64,
+            events: Vec::new(),
+            etag: None,
+            started_at: None,
+            finished_at: None,
+        }
+    }
+
+    /// Changes the status of the download and logs the transition as an event. The wall
+    /// clock time is recorded as start of the download when it starts running and as its
+    /// end when it finishes. Downloads finishing without running start and end at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The new status of the download.
+    /// * `clock` - The clock to read the time of the transition from.
+    fn transition(&mut self, status: DownloadStatus, clock: &dyn Clock) {
+        let event = match &status {
+            DownloadStatus::Successful => "completed".to_string(),
+            DownloadStatus::Failed(ref err) => format!("failed: {}", err),
+            DownloadStatus::Pending => "pending".to_string(),
+            DownloadStatus::Running => "started".to_string(),
+        };
+        match &status {
+            DownloadStatus::Running => self.started_at = Some(clock.system_time()),
+            DownloadStatus::Successful | DownloadStatus::Failed(_) => {
+                let now = clock.system_time();
+                self.started_at.get_or_insert(now);
+                self.finished_at = Some(now);
+            }
+            DownloadStatus::Pending => {}
+        }
+        self.log_event(event, clock.now());
+        self.status = status;
+    }
+
+    /// Logs an event of the download.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The description of the event.
+    /// * `time` - The point in time the event occurred.
+    fn log_event<S: Into<String>>(&mut self, event: S, time: Instant) {
+        self.events.push((time, event.into()));
+    }
+
+    /// Returns all logged events in chronological order together with their time offset
+    /// from the first event.
+    fn get_events(&self) -> Vec<(Duration, String)> {
+        match self.events.first() {
+            Some((start, _)) => self
+                .events
+                .iter()
+                .map(|(time, event)| (time.saturating_duration_since(*start), event.clone()))
+                .collect(),
+            None => Vec::new(),
         }
     }
 
@@ -318,8 +990,200 @@ impl Display for Download {
     }
 }
 
+/// A writer placing ranges of data at their offsets within a file, so that
+/// ranges can be written in any order.
+///
+/// # Examples
+/// ```
+/// use phyrexian_library::utility::download::RangeWriter;
+///
+/// let path = std::env::temp_dir().join("phyrexian_library_range_writer_doc");
+/// let mut writer = RangeWriter::new(std::fs::File::create(&path).unwrap());
+/// writer.write_range(3, b"def").unwrap();
+/// writer.write_range(0, b"abc").unwrap();
+/// assert_eq!(writer.written(), 6);
+/// assert_eq!(std::fs::read(&path).unwrap(), b"abcdef");
+/// std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct RangeWriter {
+    file: File,
+    written: u64,
+}
+
+impl RangeWriter {
+    /// Creates a new `RangeWriter` writing to the specified file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The file to write to.
+    pub fn new(file: File) -> Self {
+        RangeWriter { file, written: 0 }
+    }
+
+    /// Writes the data to the file starting at the specified offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The position in the file the data starts at.
+    /// * `data` - The data to write.
+    ///
+    /// # Errors
+    /// Returns an error if seeking or writing the file failed.
+    pub fn write_range(&mut self, offset: u64, data: &[u8]) -> io::Result<()> {
+        self.file.seek(SeekFrom::Start(offset))?;
+        self.file.write_all(data)?;
+        self.written += data.len() as u64;
+        Ok(())
+    }
+
+    /// Returns the total number of bytes written across all ranges.
+    pub fn written(&self) -> u64 {
+        self.written
+    }
+
+    /// Returns the underlying file.
+    pub fn into_inner(self) -> File {
+        self.file
+    }
+}
+
+/// Measures the download speed averaged over [`DOWNLOAD_SPEED_INTERVAL`].
+///
+/// [`DOWNLOAD_SPEED_INTERVAL`]: ./constant.DOWNLOAD_SPEED_INTERVAL.html
+#[derive(Debug)]
+struct SpeedMeter {
+    interval_start: Instant,
+    interval_written: u64,
+}
+
+impl SpeedMeter {
+    /// Creates a new `SpeedMeter` starting its first interval now.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - The clock to read the time from.
+    fn new(clock: &dyn Clock) -> Self {
+        SpeedMeter {
+            interval_start: clock.now(),
+            interval_written: 0,
+        }
+    }
+
+    /// Returns the speed in byte/sec if the current measurement interval has passed
+    /// and starts a new interval. Returns `None` if the interval has not passed yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `written` - The total number of bytes written so far.
+    /// * `clock` - The clock to read the time from.
+    fn update(&mut self, written: u64, clock: &dyn Clock) -> Option<f64> {
+        let now = clock.now();
+        let time = now.saturating_duration_since(self.interval_start);
+        if time >= DOWNLOAD_SPEED_INTERVAL {
+            let speed = ((written - self.interval_written) * 1_000_000_000_u64) as f64
+                / time.as_nanos() as f64;
+            self.interval_start = now;
+            self.interval_written = written;
+            Some(speed)
+        } else {
+            None
+        }
+    }
+}
+
+/// Limits the rate at which data is written by one or more downloads by pausing the
+/// calling thread. The rate is averaged over intervals of [`DOWNLOAD_SPEED_INTERVAL`].
+///
+/// [`DOWNLOAD_SPEED_INTERVAL`]: ./constant.DOWNLOAD_SPEED_INTERVAL.html
+#[derive(Debug)]
+struct RateLimiter {
+    max_bytes_per_second: u64,
+    /// The start of the current interval and the bytes written since, if any were written.
+    interval: Mutex<Option<(Instant, u64)>>,
+}
+
+impl RateLimiter {
+    /// Creates a new `RateLimiter`, which starts its first interval once data is written.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_bytes_per_second` - The maximum rate in byte/sec. This must not be zero.
+    fn new(max_bytes_per_second: u64) -> Self {
+        RateLimiter {
+            max_bytes_per_second,
+            interval: Mutex::new(None),
+        }
+    }
+
+    /// Records the written bytes and pauses the calling thread until the average rate
+    /// of the current interval does not exceed the limit anymore. The pause is interrupted
+    /// if the download is cancelled in the meantime.
+    ///
+    /// # Arguments
+    ///
+    /// * `written` - The number of bytes written since the last call.
+    /// * `configuration` - The settings applied to the download.
+    ///
+    /// # Errors
+    /// Returns an error if the download was cancelled.
+    fn throttle(
+        &self,
+        written: u64,
+        configuration: &DownloadConfiguration,
+    ) -> Result<(), DownloadError> {
+        let mut delay = {
+            let mut interval = self.interval.lock();
+            let now = configuration.clock.now();
+            let (start, interval_written) = (*interval)
+                .filter(|(start, _)| {
+                    now.saturating_duration_since(*start) < DOWNLOAD_SPEED_INTERVAL
+                })
+                .unwrap_or((now, 0));
+            let interval_written = interval_written + written;
+            *interval = Some((start, interval_written));
+            let required = interval_written as f64 / self.max_bytes_per_second as f64;
+            Duration::try_from_secs_f64(required)
+                .unwrap_or(Duration::MAX)
+                .saturating_sub(now.saturating_duration_since(start))
+        };
+        // The pause is measured by sleeping rather than by the clock, so that downloads
+        // with a clock that does not advance on its own do not pause forever.
+        while !delay.is_zero() {
+            check_cancelled(configuration)?;
+            let pause = delay.min(CANCELLATION_CHECK_INTERVAL);
+            std::thread::sleep(pause);
+            delay -= pause;
+        }
+        check_cancelled(configuration)
+    }
+}
+
 // End user interaction without Arc or Mutex.
 #[derive(Debug)]
+/// A handle to inspect the state of a [`Download`] managed by a [`DownloadManager`].
+/// The state is guarded by a non-poisoning lock, so all methods return plain values.
+///
+/// # Examples
+///
+/// ```
+/// use phyrexian_library::utility::download::DownloadManager;
+///
+/// let output = std::env::temp_dir().join("phyrexian_library_proxy_doc_test.json");
+/// let mut manager = DownloadManager::new().unwrap();
+/// manager.download("not a valid link", &output);
+/// while manager.has_active() {
+///     std::thread::sleep(std::time::Duration::from_millis(10));
+/// }
+/// let proxy = manager.get_download(&output).unwrap();
+/// assert!(proxy.is_failed());
+/// assert!(proxy.get_error().is_some());
+/// assert_eq!(proxy.get_downloaded_size(), 0);
+/// assert_eq!(proxy.get_download_speed(), None);
+/// assert_eq!(proxy.eta(), None);
+/// ```
+///
+/// [`Download`]: ./struct.Download.html
 pub struct DownloadProxy {
     download: Arc<Mutex<Download>>,
 }
@@ -365,12 +1229,92 @@ impl DownloadProxy {
         self.download.lock().get_downloaded_size()
     }
 
+    /// Returns the total size of the downloaded file if known.
+    pub fn get_total_size(&self) -> Option<u64> {
+        self.download.lock().total_size
+    }
+
     /// Returns the current download speed in byte/sec if the [`Download`] is running.
     ///
     /// [`Download`]: ./struct.Download.html
     pub fn get_download_speed(&self) -> Option<f64> {
         self.download.lock().get_download_speed()
     }
+
+    /// Returns the point in time the [`Download`] started if it has started yet.
+    ///
+    /// [`Download`]: ./struct.Download.html
+    pub fn started_at(&self) -> Option<SystemTime> {
+        self.download.lock().started_at
+    }
+
+    /// Returns the point in time the [`Download`] finished if it has finished yet.
+    ///
+    /// [`Download`]: ./struct.Download.html
+    pub fn finished_at(&self) -> Option<SystemTime> {
+        self.download.lock().finished_at
+    }
+
+    /// Returns how long the [`Download`] took. Returns `None` if the [`Download`] has not
+    /// finished yet or if the system time was adjusted backwards in the meantime.
+    ///
+    /// [`Download`]: ./struct.Download.html
+    pub fn duration(&self) -> Option<Duration> {
+        let download = self.download.lock();
+        download
+            .finished_at?
+            .duration_since(download.started_at?)
+            .ok()
+    }
+
+    /// Returns the fraction of the [`Download`] that has been completed, ranging from `0.0`
+    /// to `1.0`. A successful [`Download`] is always completed, even if its body was empty.
+    /// Returns `None` if the [`Download`] has not finished and its total size is unknown
+    /// or zero.
+    ///
+    /// [`Download`]: ./struct.Download.html
+    pub fn progress(&self) -> Option<f64> {
+        let download = self.download.lock();
+        if download.status.is_successful() {
+            return Some(1.0);
+        }
+        match download.total_size {
+            Some(total_size) if total_size > 0 => {
+                Some((download.get_downloaded_size() as f64 / total_size as f64).min(1.0))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the estimated time until the [`Download`] is completed. The estimate is
+    /// only available while the [`Download`] is running, its total size is known and data
+    /// is currently being received.
+    ///
+    /// [`Download`]: ./struct.Download.html
+    pub fn eta(&self) -> Option<Duration> {
+        let download = self.download.lock();
+        let speed = download.get_download_speed().filter(|speed| *speed > 0.0)?;
+        let remaining = download
+            .total_size?
+            .saturating_sub(download.get_downloaded_size());
+        Duration::try_from_secs_f64(remaining as f64 / speed).ok()
+    }
+
+    /// Returns the events logged by the [`Download`] in chronological order. Each event is
+    /// paired with its time offset from the start of the [`Download`].
+    ///
+    /// [`Download`]: ./struct.Download.html
+    pub fn events(&self) -> Vec<(Duration, String)> {
+        self.download.lock().get_events()
+    }
+
+    /// Returns the ETag the server associated with the downloaded file if any.
+    /// Before the [`Download`] has finished this is the ETag it was started with.
+    ///
+    /// [`Download`]: ./struct.Download.html
+    pub fn etag(&self) -> Option<String> {
+        self.download.lock().etag.clone()
+    }
 }
 
 impl Display for DownloadProxy {
@@ -379,113 +1323,369 @@ impl Display for DownloadProxy {
     }
 }
 
-fn download_to_file<U>(link: U, output: Arc<PathBuf>, download: Arc<Mutex<Download>>)
+/// Downloads the content of the link to the output file on the calling thread and blocks
+/// until the download is finished. The download is written to a temporary file first just
+/// like downloads started by a [`DownloadManager`] with default settings.
+///
+/// # Arguments
+///
+/// * `link` - The link to download from.
+/// * `output` - The path of the file to save the download to.
+///
+/// # Examples
+/// ```no_run
+/// use phyrexian_library::utility::download::download_file_blocking;
+///
+/// if let Err(err) = download_file_blocking("https://mtgjson.com/api/v5/Meta.json", "Meta.json") {
+///     eprintln!("The download failed: {}", err);
+/// }
+/// ```
+///
+/// # Errors
+/// Returns an error if the file could not be downloaded or written.
+///
+/// [`DownloadManager`]: ./struct.DownloadManager.html
+pub fn download_file_blocking<U, P>(link: U, output: P) -> Result<(), DownloadError>
 where
     U: reqwest::IntoUrl,
+    P: AsRef<Path>,
 {
-    download.lock().status = DownloadStatus::Running;
+    perform_download(
+        link,
+        output.as_ref(),
+        &Mutex::new(Download::pending()),
+        &DownloadConfiguration::default(),
+    )
+}
 
-    let url = match link.into_url() {
-        Ok(url) => url,
-        Err(err) => {
-            fail_download(DownloadError::from(err), download);
-            return;
-        }
-    };
+fn download_to_file<U>(
+    link: U,
+    output: Arc<PathBuf>,
+    download: Arc<Mutex<Download>>,
+    configuration: DownloadConfiguration,
+) where
+    U: reqwest::IntoUrl,
+{
+    let clock = configuration.clock.as_ref();
+    download
+        .lock()
+        .transition(DownloadStatus::Running, clock);
+    match perform_download(link, &output, &download, &configuration) {
+        Ok(()) => download
+            .lock()
+            .transition(DownloadStatus::Successful, clock),
+        Err(err) => fail_download(err, download, clock),
+    }
+}
 
-    let mut response = match reqwest::get(url) {
-        Ok(resp) => resp,
-        Err(err) => {
-            fail_download(DownloadError::from(err), download);
-            return;
-        }
+/// Downloads the content of the link to the output file, updating the progress
+/// of the download on the way.
+///
+/// # Arguments
+///
+/// * `link` - The link to download from.
+/// * `output` - The path of the file to save the download to.
+/// * `download` - The download to report the progress to.
+/// * `configuration` - The settings to apply to the download.
+fn perform_download<U>(
+    link: U,
+    output: &Path,
+    download: &Mutex<Download>,
+    configuration: &DownloadConfiguration,
+) -> Result<(), DownloadError>
+where
+    U: reqwest::IntoUrl,
+{
+    check_cancelled(configuration)?;
+    let url = link.into_url()?;
+    let client = match &configuration.client {
+        Some(client) => Arc::clone(client),
+        // Compressed responses must not be decompressed by the client, so the progress
+        // can track the compressed data received.
+        None => Arc::new(
+            reqwest::Client::builder()
+                .gzip(!configuration.decompress)
+                .build()?,
+        ),
     };
+    let mut request = client.get(url);
+    // The client does not negotiate compression itself if it does not decompress.
+    if configuration.decompress {
+        request = request.header(ACCEPT_ENCODING, "gzip");
+    }
+    let etag = download.lock().etag.clone();
+    if let Some(etag) = etag {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+    let mut response = request.send()?;
+    let response_etag = response
+        .headers()
+        .get(ETAG)
+        .and_then(|etag| etag.to_str().ok())
+        .map(String::from);
 
-    if !response.status().is_success() {
-        // TODO: Custom error
-        fail_download(
-            DownloadError::from(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!("{:?} status code.", response.status()),
-            )),
-            download,
-        );
-        return;
-    }
-
-    if let Some(Ok(Ok(length))) = response.headers().get(CONTENT_LENGTH).map(|con_len| {
-        con_len
-            .to_str()
-            .map(|con_len_str| u64::from_str(con_len_str))
-    }) {
-        download.lock().total_size = Some(length);
+    if response.status() == StatusCode::NOT_MODIFIED {
+        let mut download = download.lock();
+        if response_etag.is_some() {
+            download.etag = response_etag;
+        }
+        download.log_event("skipped: the file was not modified", configuration.clock.now());
+        return Ok(());
     }
-    if output.is_dir() {
-        fail_download(
-            DownloadError::from(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!("{:?} is a folder, not a file.", output),
-            )),
-            download,
-        );
-        return;
+    check_status(response.status())?;
+    if let Some(length) = content_length(response.headers()) {
+        download.lock().total_size = Some(length);
     }
+    download.lock().etag = response_etag;
+    let gzip = configuration.decompress
+        && (response
+            .headers()
+            .get_all(CONTENT_ENCODING)
+            .iter()
+            .any(|encoding| encoding == "gzip")
+            || response.url().path().ends_with(GZIP_FILE_EXTENSION));
+    prepare_output(output)?;
 
-    let parent_path = output
-        .parent()
-        .expect("This cannot fail as the download path must point to a file.");
-    if let Err(err) = fs::create_dir_all(parent_path) {
-        fail_download(DownloadError::from(err), download);
-        return;
+    if configuration.temporary_file {
+        let temporary_path = temporary_file_path(output);
+        let result = write_response(&mut response, &temporary_path, download, configuration, gzip);
+        complete_temporary_file(result, &temporary_path, output)
+    } else {
+        write_response(&mut response, output, download, configuration, gzip)
     }
+}
 
-    let mut dl_file = match OpenOptions::new()
+/// Writes the body of the response to the specified file, replacing any previous content.
+///
+/// # Arguments
+///
+/// * `response` - The response to read the body from.
+/// * `path` - The path of the file to write to.
+/// * `download` - The download to report the progress to.
+/// * `configuration` - The settings to apply to the download.
+/// * `gzip` - Whether the body is gzip-compressed and must be decompressed.
+fn write_response<R: Read>(
+    response: &mut R,
+    path: &Path,
+    download: &Mutex<Download>,
+    configuration: &DownloadConfiguration,
+    gzip: bool,
+) -> Result<(), DownloadError> {
+    let clock = configuration.clock.as_ref();
+    let mut dl_file = OpenOptions::new()
         .read(true)
         .write(true)
         .create(true)
-        .append(false)
-        .open(output.as_path())
-    {
-        Ok(file) => file,
-        Err(err) => {
-            fail_download(DownloadError::from(err), download);
-            return;
-        }
+        .truncate(true)
+        .open(path)?;
+    let received = Cell::new(0u64);
+    let body = CountingReader {
+        inner: response,
+        count: &received,
+    };
+    let mut body: Box<dyn Read + '_> = if gzip {
+        Box::new(GzDecoder::new(body))
+    } else {
+        Box::new(body)
     };
-    let mut buf = [0; 128 * 1024];
-    let mut written = 0u64;
-    let mut written_update = 0;
-    let mut t_start = std::time::SystemTime::now();
+    let mut buf = vec![0; configuration.buffer_size];
+    let mut throttled = 0u64;
+    let mut speed_meter = SpeedMeter::new(clock);
     loop {
-        if let Ok(time) = t_start.elapsed() {
-            if time >= DOWNLOAD_SPEED_INTERVAL {
-                download.lock().speed = ((written - written_update) * 1_000_000_000_u64) as f64
-                    / time.as_nanos() as f64;
-                t_start = std::time::SystemTime::now();
-                written_update = written;
-            }
+        check_cancelled(configuration)?;
+        if let Some(speed) = speed_meter.update(received.get(), clock) {
+            download.lock().speed = speed;
         }
-        let length = match response.read(&mut buf) {
+        let length = match body.read(&mut buf) {
             Ok(0) => break, // EOF.
             Ok(length) => length,
             Err(ref err) if err.kind() == io::ErrorKind::Interrupted => continue,
-            Err(err) => {
-                fail_download(DownloadError::from(err), download);
-                return;
-            }
+            Err(err) => return Err(DownloadError::from(err)),
         };
-        if let Err(err) = dl_file.write_all(&buf[..length]) {
-            fail_download(DownloadError::from(err), download);
-            return;
+        dl_file.write_all(&buf[..length])?;
+        download.lock().downloaded_size = received.get();
+        if let Some(rate_limiter) = &configuration.rate_limiter {
+            rate_limiter.throttle(received.get() - throttled, configuration)?;
+            throttled = received.get();
+        }
+    }
+    // The size of responses without a content length, e.g. empty bodies, is only known
+    // once they have been received completely.
+    let mut download = download.lock();
+    download.downloaded_size = received.get();
+    download.total_size.get_or_insert(received.get());
+    Ok(())
+}
+
+/// A reader counting the bytes read from the underlying reader.
+struct CountingReader<'a, R> {
+    inner: R,
+    count: &'a Cell<u64>,
+}
+
+impl<R: Read> Read for CountingReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let length = self.inner.read(buf)?;
+        self.count.set(self.count.get() + length as u64);
+        Ok(length)
+    }
+}
+
+/// Returns an error if the status code of a response does not indicate success.
+///
+/// # Arguments
+///
+/// * `status` - The status code of the response.
+fn check_status(status: StatusCode) -> Result<(), DownloadError> {
+    if status.is_success() {
+        Ok(())
+    } else {
+        // TODO: Custom error
+        Err(DownloadError::from(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{:?} status code.", status),
+        )))
+    }
+}
+
+/// Returns the length of the body announced by the headers of a response if any.
+///
+/// # Arguments
+///
+/// * `headers` - The headers of the response.
+fn content_length(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(CONTENT_LENGTH)
+        .and_then(|length| length.to_str().ok())
+        .and_then(|length| u64::from_str(length).ok())
+}
+
+/// Checks that the output path does not point to a folder and creates the folder
+/// containing the output file if it does not exist yet.
+///
+/// # Arguments
+///
+/// * `output` - The path of the file to save the download to.
+fn prepare_output(output: &Path) -> Result<(), DownloadError> {
+    if output.is_dir() {
+        return Err(DownloadError::from(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{:?} is a folder, not a file.", output),
+        )));
+    }
+    let parent_path = output
+        .parent()
+        .expect("This cannot fail as the download path must point to a file.");
+    fs::create_dir_all(parent_path)?;
+    Ok(())
+}
+
+/// Replaces the output file with the temporary file the download was written to if the
+/// download succeeded and removes the temporary file otherwise.
+///
+/// # Arguments
+///
+/// * `result` - The result of writing the download to the temporary file.
+/// * `temporary_path` - The path of the temporary file.
+/// * `output` - The path of the file to save the download to.
+fn complete_temporary_file(
+    result: Result<(), DownloadError>,
+    temporary_path: &Path,
+    output: &Path,
+) -> Result<(), DownloadError> {
+    let result =
+        result.and_then(|_| fs::rename(temporary_path, output).map_err(DownloadError::from));
+    if result.is_err() {
+        // The temporary file is of no use anymore, so failing to remove it is not an issue.
+        let _ = fs::remove_file(temporary_path);
+    }
+    result
+}
+
+/// Returns an error if the downloads of the configuration have been cancelled.
+///
+/// # Arguments
+///
+/// * `configuration` - The settings applied to the download.
+fn check_cancelled(configuration: &DownloadConfiguration) -> Result<(), DownloadError> {
+    if configuration.cancelled.load(Ordering::SeqCst) {
+        Err(DownloadError::from(io::Error::new(
+            io::ErrorKind::Interrupted,
+            "The download was cancelled.",
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Resolves the placeholders of an output file template with the components of the URL.
+///
+/// # Arguments
+///
+/// * `url` - The URL to resolve the placeholders with.
+/// * `template` - The template to resolve.
+///
+/// # Errors
+/// Returns an error if the template contains an unknown or unclosed placeholder or if a
+/// placeholder cannot be resolved from the URL.
+fn resolve_template(url: &reqwest::Url, template: &str) -> Result<String, DownloadError> {
+    let invalid =
+        |message: String| DownloadError::from(io::Error::new(io::ErrorKind::InvalidInput, message));
+    let filename = url
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .unwrap_or_default();
+    let (stem, extension) = match filename.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, extension),
+        _ => (filename, ""),
+    };
+    let mut resolved = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        resolved.push_str(&rest[..start]);
+        let end = rest[start..].find('}').ok_or_else(|| {
+            invalid(format!("The template {} has an unclosed placeholder.", template))
+        })?;
+        let placeholder = &rest[start + 1..start + end];
+        let value = match placeholder {
+            "host" => url.host_str().unwrap_or_default(),
+            "filename" => filename,
+            "stem" => stem,
+            "ext" => extension,
+            _ => return Err(invalid(format!("{{{}}} is not a valid placeholder.", placeholder))),
         };
-        written += length as u64;
-        download.lock().downloaded_size = written;
+        if value.is_empty() {
+            return Err(invalid(format!(
+                "The placeholder {{{}}} cannot be resolved from {}.",
+                placeholder, url
+            )));
+        }
+        resolved.push_str(value);
+        rest = &rest[start + end + 1..];
     }
-    download.lock().status = DownloadStatus::Successful;
+    resolved.push_str(rest);
+    Ok(resolved)
+}
+
+/// Returns the path of the temporary file a download to the specified output file
+/// is written to.
+///
+/// # Arguments
+///
+/// * `output` - The path of the output file.
+fn temporary_file_path(output: &Path) -> PathBuf {
+    let mut file_name = output
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    file_name.push(TEMPORARY_FILE_EXTENSION);
+    output.with_file_name(file_name)
 }
 
-fn fail_download(failure: DownloadError, download: Arc<Mutex<Download>>) {
-    download.lock().status = DownloadStatus::Failed(Arc::new(failure));
+fn fail_download(failure: DownloadError, download: Arc<Mutex<Download>>, clock: &dyn Clock) {
+    download
+        .lock()
+        .transition(DownloadStatus::Failed(Arc::new(failure)), clock);
 }
 
 #[cfg(test)]
@@ -0,0 +1,187 @@
+//! The `asynchronous` module provides downloads that are driven by a tokio runtime
+//! instead of the thread pool of a [`DownloadManager`]. It is only available if the
+//! `async` feature is enabled.
+//!
+//! [`DownloadManager`]: ../struct.DownloadManager.html
+
+extern crate futures;
+extern crate tokio_threadpool;
+
+use super::{
+    check_status, complete_temporary_file, content_length, prepare_output, temporary_file_path,
+    Clock, Download, DownloadConfiguration, DownloadError, DownloadProxy, DownloadStatus,
+    SpeedMeter,
+};
+use futures::{future, Future, Stream};
+use parking_lot::Mutex;
+use reqwest::r#async::{Client, Response};
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Downloads a file via HTTP or HTTPS asynchronously. The returned future streams the
+/// response body chunk by chunk to a temporary file, which replaces the output file once
+/// the download is complete. It must be run on the thread pool of a tokio runtime, which
+/// performs the file system access in blocking sections, so that other futures are not
+/// blocked by it. The progress of the download can be tracked via the returned
+/// [`DownloadProxy`] just like the downloads of a [`DownloadManager`].
+///
+/// # Arguments
+///
+/// * `link` - A URL to a file, which should be downloaded.
+/// * `output` - A path specifying the file to which the downloaded data is written.
+///
+/// # Examples
+/// ```no_run
+/// use phyrexian_library::utility::download::asynchronous::download_async;
+///
+/// let (proxy, download) = download_async("https://mtgjson.com/api/v5/Meta.json", "Meta.json");
+/// let mut runtime = tokio::runtime::Runtime::new().unwrap();
+/// if let Err(err) = runtime.block_on(download) {
+///     eprintln!("The download failed: {}", err);
+/// }
+/// assert!(proxy.is_successful());
+/// ```
+///
+/// [`DownloadManager`]: ../struct.DownloadManager.html
+/// [`DownloadProxy`]: ../struct.DownloadProxy.html
+pub fn download_async<U, P>(
+    link: U,
+    output: P,
+) -> (
+    DownloadProxy,
+    impl Future<Item = (), Error = Arc<DownloadError>> + Send + 'static,
+)
+where
+    U: reqwest::IntoUrl + Send + 'static,
+    P: AsRef<Path>,
+{
+    let download = Arc::new(Mutex::new(Download::pending()));
+    let proxy = DownloadProxy {
+        download: Arc::clone(&download),
+    };
+    let output = output.as_ref().to_path_buf();
+    let clock = DownloadConfiguration::default().clock;
+    let running = Arc::clone(&download);
+    let progress = Arc::clone(&download);
+    let running_clock = Arc::clone(&clock);
+    let progress_clock = Arc::clone(&clock);
+    let future = future::lazy(move || {
+        running
+            .lock()
+            .transition(DownloadStatus::Running, running_clock.as_ref());
+        let url = link.into_url()?;
+        Ok::<_, DownloadError>(Client::builder().build()?.get(url).send().from_err())
+    })
+    .flatten()
+    .and_then(move |response| write_response(response, output, progress, progress_clock))
+    .then(move |result| match result {
+        Ok(()) => {
+            download
+                .lock()
+                .transition(DownloadStatus::Successful, clock.as_ref());
+            Ok(())
+        }
+        Err(err) => {
+            let err = Arc::new(err);
+            download
+                .lock()
+                .transition(DownloadStatus::Failed(Arc::clone(&err)), clock.as_ref());
+            Err(err)
+        }
+    });
+    (proxy, future)
+}
+
+/// Streams the body of the response to the output file, updating the progress
+/// of the download on the way.
+///
+/// # Arguments
+///
+/// * `response` - The response to read the body from.
+/// * `output` - The path of the file to save the download to.
+/// * `download` - The download to report the progress to.
+/// * `clock` - The clock to measure the progress of the download with.
+fn write_response(
+    response: Response,
+    output: PathBuf,
+    download: Arc<Mutex<Download>>,
+    clock: Arc<dyn Clock>,
+) -> impl Future<Item = (), Error = DownloadError> {
+    let temporary_path = temporary_file_path(&output);
+    let checked = check_status(response.status()).map(|()| {
+        if let Some(length) = content_length(response.headers()) {
+            download.lock().total_size = Some(length);
+        }
+    });
+    let created_path = temporary_path.clone();
+    let created_output = output.clone();
+    let completed = Arc::clone(&download);
+    future::result(checked)
+        .and_then(move |()| {
+            blocking(move || {
+                prepare_output(&created_output)?;
+                Ok(File::create(&created_path)?)
+            })
+        })
+        .and_then(move |file| {
+            let speed_meter = SpeedMeter::new(clock.as_ref());
+            response.into_body().from_err().fold(
+                (file, 0u64, speed_meter),
+                move |(mut file, written, mut speed_meter), chunk| {
+                    let written = written + chunk.len() as u64;
+                    let download = Arc::clone(&download);
+                    let clock = Arc::clone(&clock);
+                    blocking(move || {
+                        file.write_all(&chunk)?;
+                        Ok(file)
+                    })
+                    .map(move |file| {
+                        let mut download = download.lock();
+                        download.downloaded_size = written;
+                        if let Some(speed) = speed_meter.update(written, clock.as_ref()) {
+                            download.speed = speed;
+                        }
+                        (file, written, speed_meter)
+                    })
+                },
+            )
+        })
+        .map(move |(_, written, _)| {
+            // The size of responses without a content length is only known once they have
+            // been received completely.
+            completed.lock().total_size.get_or_insert(written);
+        })
+        .then(move |result| {
+            blocking(move || complete_temporary_file(result, &temporary_path, &output))
+        })
+}
+
+/// Runs a blocking operation, such as file system access, on the current thread of the
+/// tokio thread pool. The pool hands its other futures to different threads in the
+/// meantime, so they are not blocked by the operation.
+///
+/// # Arguments
+///
+/// * `operation` - The blocking operation to run.
+///
+/// # Errors
+/// Returns an error if the operation failed or if the future is not run on a tokio
+/// thread pool.
+fn blocking<F, T>(operation: F) -> impl Future<Item = T, Error = DownloadError>
+where
+    F: FnOnce() -> Result<T, DownloadError>,
+{
+    let mut operation = Some(operation);
+    future::poll_fn(move || {
+        tokio_threadpool::blocking(|| {
+            let operation = operation
+                .take()
+                .expect("The operation is only run once the pool has capacity.");
+            operation()
+        })
+        .map_err(|err| DownloadError::from(io::Error::other(err)))
+    })
+    .and_then(future::result)
+}
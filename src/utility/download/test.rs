@@ -1,11 +1,10 @@
 use super::*;
+use mock_server::MockServer;
 
 fn new_download(status: DownloadStatus) -> Arc<Mutex<Download>> {
     Arc::new(Mutex::new(Download {
         status,
-        downloaded_size: 0,
-        total_size: None,
-        speed: 0f64,
+        ..Download::pending()
     }))
 }
 
@@ -16,6 +15,11 @@ where
     Arc::new(path.as_ref().to_path_buf())
 }
 
+/// Returns a path in the temporary directory unique to this process and the specified name.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("phyrexian_library_{}_{}", std::process::id(), name))
+}
+
 #[test]
 fn test_fail_download() {
     let download = new_download(DownloadStatus::Pending);
@@ -24,9 +28,151 @@ fn test_fail_download() {
         io::ErrorKind::InvalidInput,
         "This is a test error.",
     ));
-    fail_download(err, Arc::clone(&download));
+    fail_download(err, Arc::clone(&download), &SystemClock);
     assert!(download.lock().status.is_failed());
 }
 
+#[test]
+fn test_download_events() {
+    let clock = MockClock::new();
+    let download = new_download(DownloadStatus::Pending);
+    download
+        .lock()
+        .transition(DownloadStatus::Running, &clock);
+    clock.advance(Duration::from_millis(300));
+    download.lock().log_event("custom", clock.now());
+    clock.advance(Duration::from_millis(200));
+    fail_download(
+        DownloadError::from(io::Error::new(io::ErrorKind::InvalidInput, "reason")),
+        Arc::clone(&download),
+        &clock,
+    );
+    let proxy = DownloadProxy { download };
+    assert_eq!(
+        proxy.events(),
+        vec!(
+            (Duration::from_millis(0), "started".to_string()),
+            (Duration::from_millis(300), "custom".to_string()),
+            (Duration::from_millis(500), "failed: reason".to_string()),
+        )
+    );
+}
+
+#[test]
+fn test_download_events_invalid_url() {
+    let download = new_download(DownloadStatus::Pending);
+    assert!(DownloadProxy {
+        download: Arc::clone(&download)
+    }
+    .events()
+    .is_empty());
+    download_to_file(
+        "not a url",
+        new_path("/invalid"),
+        Arc::clone(&download),
+        DownloadConfiguration {
+            clock: Arc::new(MockClock::new()),
+            ..DownloadConfiguration::default()
+        },
+    );
+    let events: Vec<String> = DownloadProxy { download }
+        .events()
+        .into_iter()
+        .map(|(_, event)| event)
+        .collect();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0], "started");
+    assert!(events[1].starts_with("failed: "));
+}
+
+#[test]
+fn test_temporary_file_path() {
+    assert_eq!(
+        temporary_file_path(Path::new("/sets/ABC.json")),
+        PathBuf::from("/sets/ABC.json.part")
+    );
+}
+
+/// Tests if a failure during the transfer leaves an already existing output file intact.
+#[test]
+fn test_temporary_file_keeps_original_on_failure() {
+    let server = MockServer::start(|_| mock_server::truncated_response(1024, b"partial"));
+    let output = new_path(temp_path("temporary_file_failure.txt"));
+    fs::write(output.as_path(), "original").unwrap();
+    let download = new_download(DownloadStatus::Pending);
+    download_to_file(
+        server.url("/file"),
+        Arc::clone(&output),
+        Arc::clone(&download),
+        DownloadConfiguration::default(),
+    );
+    assert!(download.lock().status.is_failed());
+    assert_eq!(fs::read_to_string(output.as_path()).unwrap(), "original");
+    assert!(!temporary_file_path(&output).exists());
+    fs::remove_file(output.as_path()).unwrap();
+}
+
+/// Tests if a failed download does not create the output file.
+#[test]
+fn test_temporary_file_no_output_on_failure() {
+    let server = MockServer::start(|_| mock_server::truncated_response(1024, b"partial"));
+    let output = new_path(temp_path("temporary_file_no_output.txt"));
+    let download = new_download(DownloadStatus::Pending);
+    download_to_file(
+        server.url("/file"),
+        Arc::clone(&output),
+        Arc::clone(&download),
+        DownloadConfiguration::default(),
+    );
+    assert!(download.lock().status.is_failed());
+    assert!(!output.exists());
+    assert!(!temporary_file_path(&output).exists());
+}
+
+/// Tests if a successful download replaces the output file with the downloaded content.
+#[test]
+fn test_temporary_file_replaces_original_on_success() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"downloaded"));
+    let output = new_path(temp_path("temporary_file_success.txt"));
+    fs::write(output.as_path(), "original").unwrap();
+    let download = new_download(DownloadStatus::Pending);
+    download_to_file(
+        server.url("/file"),
+        Arc::clone(&output),
+        Arc::clone(&download),
+        DownloadConfiguration::default(),
+    );
+    assert!(download.lock().status.is_successful());
+    assert_eq!(fs::read_to_string(output.as_path()).unwrap(), "downloaded");
+    assert!(!temporary_file_path(&output).exists());
+    fs::remove_file(output.as_path()).unwrap();
+}
+
+/// Tests if a blocking download writes the file and reports success.
+#[test]
+fn test_download_file_blocking() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"blocking"));
+    let output = temp_path("blocking_download.txt");
+    assert!(download_file_blocking(server.url("/file"), &output).is_ok());
+    assert_eq!(fs::read_to_string(&output).unwrap(), "blocking");
+    assert!(!temporary_file_path(&output).exists());
+    fs::remove_file(&output).unwrap();
+}
+
+/// Tests if a blocking download reports unsuccessful responses as errors.
+#[test]
+fn test_download_file_blocking_error() {
+    let server = MockServer::start(|_| mock_server::response("404 Not Found", &[], b""));
+    let output = temp_path("blocking_download_error.txt");
+    assert!(download_file_blocking(server.url("/missing"), &output).is_err());
+    assert!(!output.exists());
+}
+
+#[cfg(feature = "async")]
+mod asynchronous_tests;
+mod clock_tests;
 mod download_manager_tests;
+mod download_proxy_tests;
 mod download_status_tests;
+mod mock_server;
+mod range_writer_tests;
@@ -0,0 +1,44 @@
+use super::*;
+use asynchronous::download_async;
+
+/// Tests if an asynchronous download writes the response body to the output file.
+#[test]
+fn test_download_async() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"asynchronous"));
+    let output = temp_path("download_async.txt");
+    let (proxy, download) = download_async(server.url("/file"), output.clone());
+    assert!(proxy.is_pending());
+    let mut runtime = tokio::runtime::Runtime::new().unwrap();
+    runtime.block_on(download).unwrap();
+    assert!(proxy.is_successful());
+    assert_eq!(proxy.get_downloaded_size(), 12);
+    assert_eq!(proxy.get_total_size(), Some(12));
+    assert_eq!(fs::read_to_string(&output).unwrap(), "asynchronous");
+    assert!(!temporary_file_path(&output).exists());
+    fs::remove_file(&output).unwrap();
+}
+
+/// Tests if an asynchronous download fails on an unsuccessful status code.
+#[test]
+fn test_download_async_failure() {
+    let server = MockServer::start(|_| mock_server::response("404 Not Found", &[], b""));
+    let output = temp_path("download_async_failure.txt");
+    let (proxy, download) = download_async(server.url("/missing"), output.clone());
+    let mut runtime = tokio::runtime::Runtime::new().unwrap();
+    assert!(runtime.block_on(download).is_err());
+    assert!(proxy.is_failed());
+    assert!(!output.exists());
+}
+
+/// Tests if an asynchronous download fails outside of a thread pool, as it cannot
+/// access the file system without blocking the runtime.
+#[test]
+fn test_download_async_current_thread() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"blocking"));
+    let output = temp_path("download_async_current_thread.txt");
+    let (proxy, download) = download_async(server.url("/file"), output.clone());
+    let mut runtime = tokio::runtime::current_thread::Runtime::new().unwrap();
+    assert!(runtime.block_on(download).is_err());
+    assert!(proxy.is_failed());
+    assert!(!output.exists());
+}
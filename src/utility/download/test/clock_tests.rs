@@ -0,0 +1,43 @@
+use super::*;
+
+#[test]
+fn test_mock_clock_advance() {
+    let clock = MockClock::new();
+    let start = clock.now();
+    assert_eq!(clock.now(), start);
+    clock.advance(Duration::from_millis(150));
+    clock.advance(Duration::from_millis(350));
+    assert_eq!(clock.now() - start, Duration::from_millis(500));
+}
+
+#[test]
+fn test_speed_meter_waits_for_interval() {
+    let clock = MockClock::new();
+    let mut meter = SpeedMeter::new(&clock);
+    assert_eq!(meter.update(1024, &clock), None);
+    clock.advance(DOWNLOAD_SPEED_INTERVAL - Duration::from_millis(1));
+    assert_eq!(meter.update(2048, &clock), None);
+}
+
+#[test]
+fn test_speed_meter_speed() {
+    let clock = MockClock::new();
+    let mut meter = SpeedMeter::new(&clock);
+    clock.advance(Duration::from_millis(250));
+    assert_eq!(meter.update(1000, &clock), Some(4000.0));
+    // The next interval only considers the bytes written since the last update.
+    clock.advance(Duration::from_millis(500));
+    assert_eq!(meter.update(1500, &clock), Some(1000.0));
+    assert_eq!(meter.update(1500, &clock), None);
+}
+
+#[test]
+fn test_manager_with_clock() {
+    let clock = Arc::new(MockClock::new());
+    let manager = DownloadManager::new()
+        .unwrap()
+        .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+    let start = clock.now();
+    clock.advance(Duration::from_secs(3));
+    assert_eq!(manager.configuration.clock.now() - start, Duration::from_secs(3));
+}
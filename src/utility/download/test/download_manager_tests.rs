@@ -44,3 +44,568 @@ fn test_size() {
     }
     assert_eq!(manager.size(), 98);
 }
+
+/// Tests if the callback of a download is invoked with the output path on success.
+#[test]
+fn test_download_with_callback_success() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"callback"));
+    let output = temp_path("callback_success.txt");
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut manager = DownloadManager::new().unwrap();
+    manager.download_with_callback(server.url("/file"), &output, move |result| {
+        sender.send(result).unwrap();
+    });
+    let result = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert_eq!(result.unwrap(), output);
+    assert!(manager.get_download(&output).unwrap().is_successful());
+    assert_eq!(fs::read_to_string(&output).unwrap(), "callback");
+    fs::remove_file(&output).unwrap();
+}
+
+/// Tests if the callback of a download is invoked with the error on failure.
+#[test]
+fn test_download_with_callback_failure() {
+    let server = MockServer::start(|_| {
+        mock_server::response("500 Internal Server Error", &[], b"")
+    });
+    let output = temp_path("callback_failure.txt");
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut manager = DownloadManager::new().unwrap();
+    manager.download_with_callback(server.url("/file"), &output, move |result| {
+        sender.send(result.map_err(|err| err.to_string())).unwrap();
+    });
+    let result = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert!(result.is_err());
+    assert!(manager.get_download(&output).unwrap().is_failed());
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_total_speed_and_downloaded() {
+    let mut manager = DownloadManager::new().unwrap();
+    assert_eq!(manager.total_speed(), 0.0);
+    assert_eq!(manager.total_downloaded(), 0);
+    let download = |status, speed, downloaded_size| {
+        Arc::new(Mutex::new(Download {
+            status,
+            speed,
+            downloaded_size,
+            ..Download::pending()
+        }))
+    };
+    let download_map = &mut manager.downloads;
+    download_map.insert(new_path("/running_a"), download(DownloadStatus::Running, 100.0, 10));
+    download_map.insert(new_path("/running_b"), download(DownloadStatus::Running, 50.5, 20));
+    download_map.insert(new_path("/success"), download(DownloadStatus::Successful, 1000.0, 300));
+    download_map.insert(new_path("/pending"), download(DownloadStatus::Pending, 0.0, 0));
+    let err = io::Error::new(io::ErrorKind::InvalidInput, "failed");
+    download_map.insert(new_path("/failed"), download(DownloadStatus::from(err), 10.0, 4000));
+    assert_eq!(manager.total_speed(), 150.5);
+    assert_eq!(manager.total_downloaded(), 4330);
+}
+
+/// Tests if an existing output file is not downloaded again.
+#[test]
+fn test_download_if_missing_existing() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"downloaded"));
+    let output = temp_path("if_missing_existing.txt");
+    fs::write(&output, "existing").unwrap();
+    let mut manager = DownloadManager::new().unwrap();
+    manager.download_if_missing(server.url("/file"), &output);
+    let download = manager.get_download(&output).unwrap();
+    assert!(download.is_successful());
+    assert_eq!(download.get_downloaded_size(), 8);
+    assert!(!manager.has_active());
+    assert!(server.requests().is_empty());
+    assert_eq!(fs::read_to_string(&output).unwrap(), "existing");
+    fs::remove_file(&output).unwrap();
+}
+
+/// Tests if missing or empty output files are downloaded.
+#[test]
+fn test_download_if_missing_empty() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"downloaded"));
+    let output = temp_path("if_missing_empty.txt");
+    fs::write(&output, "").unwrap();
+    let mut manager = DownloadManager::new().unwrap();
+    manager.download_if_missing(server.url("/file"), &output);
+    while manager.has_active() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(manager.get_download(&output).unwrap().is_successful());
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(fs::read_to_string(&output).unwrap(), "downloaded");
+    fs::remove_file(&output).unwrap();
+}
+
+/// Returns a mock server redirecting `/moved` to `/target`.
+fn redirecting_server() -> MockServer {
+    MockServer::start(|head| {
+        if head.starts_with("GET /moved ") {
+            mock_server::response("302 Found", &[("Location", "/target")], b"")
+        } else {
+            mock_server::response("200 OK", &[], b"target")
+        }
+    })
+}
+
+/// Tests if redirects are followed by default.
+#[test]
+fn test_redirect_policy_follow() {
+    let server = redirecting_server();
+    let output = temp_path("redirect_follow.txt");
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut manager = DownloadManager::new().unwrap();
+    manager.download_with_callback(server.url("/moved"), &output, move |result| {
+        sender.send(result.is_ok()).unwrap();
+    });
+    assert!(receiver.recv_timeout(Duration::from_secs(10)).unwrap());
+    assert_eq!(fs::read_to_string(&output).unwrap(), "target");
+    assert_eq!(server.requests().len(), 2);
+    fs::remove_file(&output).unwrap();
+}
+
+/// Tests if redirects are rejected if configured accordingly.
+#[test]
+fn test_redirect_policy_none() {
+    let server = redirecting_server();
+    let output = temp_path("redirect_none.txt");
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut manager = DownloadManager::new()
+        .unwrap()
+        .with_redirect_policy(reqwest::RedirectPolicy::none())
+        .unwrap();
+    manager.download_with_callback(server.url("/moved"), &output, move |result| {
+        sender.send(result.is_ok()).unwrap();
+    });
+    assert!(!receiver.recv_timeout(Duration::from_secs(10)).unwrap());
+    assert!(!output.exists());
+    assert_eq!(server.requests().len(), 1);
+}
+
+/// Tests if downloads with a small buffer produce the correct file content.
+#[test]
+fn test_buffer_size() {
+    let body: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+    let expected = body.clone();
+    let server = MockServer::start(move |_| mock_server::response("200 OK", &[], &body));
+    let output = temp_path("buffer_size.bin");
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut manager = DownloadManager::new().unwrap().with_buffer_size(1024).unwrap();
+    assert_eq!(manager.configuration.buffer_size, 1024);
+    manager.download_with_callback(server.url("/file"), &output, move |result| {
+        sender.send(result.is_ok()).unwrap();
+    });
+    assert!(receiver.recv_timeout(Duration::from_secs(10)).unwrap());
+    assert_eq!(fs::read(&output).unwrap(), expected);
+    fs::remove_file(&output).unwrap();
+}
+
+/// Tests if a buffer size of zero is rejected.
+#[test]
+fn test_buffer_size_zero() {
+    let manager = DownloadManager::new().unwrap();
+    assert_eq!(manager.configuration.buffer_size, DEFAULT_BUFFER_SIZE);
+    assert!(manager.with_buffer_size(0).is_err());
+}
+
+/// Tests if the download rate is limited.
+#[test]
+fn test_max_bytes_per_second() {
+    let body: Vec<u8> = (0..20_000u32).map(|i| (i % 251) as u8).collect();
+    let expected = body.clone();
+    let server = MockServer::start(move |_| mock_server::response("200 OK", &[], &body));
+    let output = temp_path("max_bytes_per_second.bin");
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut manager = DownloadManager::new()
+        .unwrap()
+        .with_buffer_size(1024)
+        .unwrap()
+        .with_max_bytes_per_second(40_000)
+        .unwrap();
+    let start = Instant::now();
+    manager.download_with_callback(server.url("/file"), &output, move |result| {
+        sender.send(result.is_ok()).unwrap();
+    });
+    assert!(receiver.recv_timeout(Duration::from_secs(10)).unwrap());
+    // 20 000 bytes at 40 000 byte/sec take at least half a second.
+    assert!(start.elapsed() >= Duration::from_millis(450));
+    assert_eq!(fs::read(&output).unwrap(), expected);
+    fs::remove_file(&output).unwrap();
+    assert!(DownloadManager::new().unwrap().with_max_bytes_per_second(0).is_err());
+}
+
+/// Tests if throttled downloads stop pausing once they are cancelled.
+#[test]
+fn test_max_bytes_per_second_cancelled() {
+    let body = vec![7u8; 200_000];
+    let server = MockServer::start(move |_| mock_server::response("200 OK", &[], &body));
+    let output = temp_path("max_bytes_per_second_cancelled.bin");
+    let (sender, receiver) = std::sync::mpsc::channel();
+    // A single buffer takes more than 20 minutes to write at this rate.
+    let mut manager = DownloadManager::new()
+        .unwrap()
+        .with_max_bytes_per_second(100)
+        .unwrap();
+    manager.download_with_callback(server.url("/file"), &output, move |result| {
+        sender.send(result.is_ok()).unwrap();
+    });
+    let proxy = manager.get_download(&output).unwrap();
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while proxy.get_downloaded_size() == 0 {
+        assert!(Instant::now() < deadline, "The download did not start.");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let start = Instant::now();
+    manager.clear();
+    assert!(!receiver.recv_timeout(Duration::from_secs(10)).unwrap());
+    assert!(start.elapsed() < Duration::from_secs(1));
+    assert!(proxy.is_failed());
+    assert!(!output.exists());
+}
+
+/// Tests if dropping the manager cancels running downloads.
+#[test]
+fn test_drop_cancels_downloads() {
+    let body = vec![7u8; 200_000];
+    let server = MockServer::start(move |_| mock_server::response("200 OK", &[], &body));
+    let output = temp_path("drop_cancels.bin");
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut manager = DownloadManager::new()
+        .unwrap()
+        .with_temporary_file(false)
+        .with_buffer_size(1024)
+        .unwrap()
+        .with_max_bytes_per_second(50_000)
+        .unwrap();
+    manager.download_with_callback(server.url("/file"), &output, move |result| {
+        sender.send(result.is_ok()).unwrap();
+    });
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while fs::metadata(&output).map(|metadata| metadata.len()).unwrap_or(0) == 0 {
+        assert!(Instant::now() < deadline, "The download did not start.");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let proxy = manager.get_download(&output).unwrap();
+    drop(manager);
+    assert!(!receiver.recv_timeout(Duration::from_secs(10)).unwrap());
+    assert!(proxy.is_failed());
+    let size = fs::metadata(&output).unwrap().len();
+    assert!(size < 200_000);
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(fs::metadata(&output).unwrap().len(), size);
+    fs::remove_file(&output).unwrap();
+}
+
+/// Tests if shutting down the manager removes the temporary files of cancelled downloads.
+#[test]
+fn test_shutdown_removes_temporary_files() {
+    let body = vec![7u8; 200_000];
+    let server = MockServer::start(move |_| mock_server::response("200 OK", &[], &body));
+    let output = temp_path("shutdown_cancels.bin");
+    let mut manager = DownloadManager::new()
+        .unwrap()
+        .with_buffer_size(1024)
+        .unwrap()
+        .with_max_bytes_per_second(50_000)
+        .unwrap();
+    manager.download(server.url("/file"), &output);
+    let temporary_path = temporary_file_path(&output);
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while fs::metadata(&temporary_path).map(|metadata| metadata.len()).unwrap_or(0) == 0 {
+        assert!(Instant::now() < deadline, "The download did not start.");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    manager.shutdown();
+    assert!(!temporary_path.exists());
+    assert!(!output.exists());
+}
+
+/// Tests if the manager can be dropped from within the callback of one of its downloads.
+#[test]
+fn test_drop_in_callback() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"callback"));
+    let output = temp_path("drop_in_callback.txt");
+    let manager = Arc::new(Mutex::new(Some(DownloadManager::new().unwrap())));
+    let shared = Arc::clone(&manager);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    manager
+        .lock()
+        .as_mut()
+        .unwrap()
+        .download_with_callback(server.url("/file"), &output, move |result| {
+            drop(shared.lock().take());
+            sender.send(result.is_ok()).unwrap();
+        });
+    assert!(receiver.recv_timeout(Duration::from_secs(10)).unwrap());
+    assert!(manager.lock().is_none());
+    assert_eq!(fs::read_to_string(&output).unwrap(), "callback");
+    fs::remove_file(&output).unwrap();
+}
+
+/// Returns a mock server answering requests carrying the ETag `"v1"` with
+/// `304 Not Modified` and all other requests with the content of version `"v2"`.
+fn etag_server() -> MockServer {
+    MockServer::start(|head| {
+        if head.to_lowercase().contains("if-none-match: \"v1\"") {
+            mock_server::response("304 Not Modified", &[("ETag", "\"v1\"")], b"")
+        } else {
+            mock_server::response("200 OK", &[("ETag", "\"v2\"")], b"version 2")
+        }
+    })
+}
+
+/// Tests if an unmodified file is not written again.
+#[test]
+fn test_download_conditional_not_modified() {
+    let server = etag_server();
+    let output = temp_path("conditional_not_modified.txt");
+    fs::write(&output, "version 1").unwrap();
+    let mut manager = DownloadManager::new().unwrap();
+    manager.download_conditional(server.url("/file"), &output, Some("\"v1\"".to_string()));
+    while manager.has_active() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let download = manager.get_download(&output).unwrap();
+    assert!(download.is_successful());
+    assert_eq!(download.etag(), Some("\"v1\"".to_string()));
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(fs::read_to_string(&output).unwrap(), "version 1");
+    fs::remove_file(&output).unwrap();
+}
+
+/// Tests if a modified file is downloaded and its new ETag is recorded.
+#[test]
+fn test_download_conditional_modified() {
+    let server = etag_server();
+    let output = temp_path("conditional_modified.txt");
+    fs::write(&output, "version 0").unwrap();
+    let mut manager = DownloadManager::new().unwrap();
+    manager.download_conditional(server.url("/file"), &output, Some("\"v0\"".to_string()));
+    while manager.has_active() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let download = manager.get_download(&output).unwrap();
+    assert!(download.is_successful());
+    assert_eq!(download.etag(), Some("\"v2\"".to_string()));
+    assert!(server.requests()[0]
+        .to_lowercase()
+        .contains("if-none-match: \"v0\""));
+    assert_eq!(fs::read_to_string(&output).unwrap(), "version 2");
+    fs::remove_file(&output).unwrap();
+}
+
+/// Tests if clearing the manager removes all downloads and cancels the active ones.
+#[test]
+fn test_clear() {
+    let mut manager = DownloadManager::new().unwrap();
+    let err = io::Error::new(io::ErrorKind::InvalidInput, "failed");
+    let download_map = &mut manager.downloads;
+    download_map.insert(new_path("/success"), new_download(DownloadStatus::Successful));
+    download_map.insert(new_path("/pending"), new_download(DownloadStatus::Pending));
+    download_map.insert(new_path("/running"), new_download(DownloadStatus::Running));
+    download_map.insert(new_path("/failed"), new_download(DownloadStatus::from(err)));
+    let cancelled = Arc::clone(&manager.configuration.cancelled);
+    assert_eq!(manager.clear().len(), 4);
+    assert_eq!(manager.size(), 0);
+    assert!(cancelled.load(Ordering::SeqCst));
+    assert!(!manager.configuration.cancelled.load(Ordering::SeqCst));
+    assert!(manager.clear().is_empty());
+}
+
+/// Tests if downloads started after clearing the manager are not cancelled.
+#[test]
+fn test_download_after_clear() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"cleared"));
+    let output = temp_path("download_after_clear.txt");
+    let mut manager = DownloadManager::new().unwrap();
+    manager.clear();
+    manager.download(server.url("/file"), &output);
+    while manager.has_active() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(manager.get_download(&output).unwrap().is_successful());
+    assert_eq!(fs::read_to_string(&output).unwrap(), "cleared");
+    fs::remove_file(&output).unwrap();
+}
+
+/// Returns the gzip-compressed data.
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// Tests if gzip-compressed downloads are decompressed.
+#[test]
+fn test_download_decompressed() {
+    let content = "{\"data\": {}}".repeat(100);
+    let compressed = gzip(content.as_bytes());
+    let compressed_length = compressed.len() as u64;
+    let server = MockServer::start(move |head| {
+        let accepts_gzip = head.to_lowercase().contains("accept-encoding: gzip");
+        if head.starts_with("GET /encoded ") && accepts_gzip {
+            mock_server::response("200 OK", &[("Content-Encoding", "gzip")], &compressed)
+        } else {
+            mock_server::response("200 OK", &[], &compressed)
+        }
+    });
+    let encoded = temp_path("decompressed_encoded.json");
+    let extension = temp_path("decompressed_extension.json");
+    let plain = temp_path("decompressed_plain.json");
+    let mut manager = DownloadManager::new().unwrap();
+    manager.download_decompressed(server.url("/encoded"), &encoded);
+    manager.download_decompressed(server.url("/AllPrintings.json.gz"), &extension);
+    manager.download_decompressed(server.url("/AllPrintings.json"), &plain);
+    while manager.has_active() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    for output in [&encoded, &extension] {
+        let download = manager.get_download(output).unwrap();
+        assert!(download.is_successful());
+        assert_eq!(download.get_downloaded_size(), compressed_length);
+        assert_eq!(download.get_total_size(), Some(compressed_length));
+        assert_eq!(fs::read_to_string(output).unwrap(), content);
+        fs::remove_file(output).unwrap();
+    }
+    assert!(manager.get_download(&plain).unwrap().is_successful());
+    assert_eq!(fs::read(&plain).unwrap(), gzip(content.as_bytes()));
+    fs::remove_file(&plain).unwrap();
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests
+        .iter()
+        .all(|request| request.to_lowercase().contains("accept-encoding: gzip")));
+}
+
+/// Tests if decompressed downloads apply the redirect policy and track compressed data.
+#[test]
+fn test_download_decompressed_redirect_policy() {
+    let content = "{\"data\": {}}".repeat(100);
+    let compressed = gzip(content.as_bytes());
+    let compressed_length = compressed.len() as u64;
+    let server = MockServer::start(move |head| {
+        if head.starts_with("GET /moved ") {
+            mock_server::response("302 Found", &[("Location", "/target")], b"")
+        } else {
+            mock_server::response("200 OK", &[("Content-Encoding", "gzip")], &compressed)
+        }
+    });
+    let moved = temp_path("decompressed_redirect_moved.json");
+    let target = temp_path("decompressed_redirect_target.json");
+    let mut manager = DownloadManager::new()
+        .unwrap()
+        .with_redirect_policy(reqwest::RedirectPolicy::none())
+        .unwrap();
+    manager.download_decompressed(server.url("/moved"), &moved);
+    manager.download_decompressed(server.url("/target"), &target);
+    while manager.has_active() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(manager.get_download(&moved).unwrap().is_failed());
+    assert!(!moved.exists());
+    let download = manager.get_download(&target).unwrap();
+    assert!(download.is_successful());
+    assert_eq!(download.get_downloaded_size(), compressed_length);
+    assert_eq!(download.get_total_size(), Some(compressed_length));
+    assert_eq!(fs::read_to_string(&target).unwrap(), content);
+    assert_eq!(server.requests().len(), 2);
+    fs::remove_file(&target).unwrap();
+}
+
+/// Tests if consecutive downloads from the same host reuse the connection of the shared client.
+#[test]
+fn test_shared_client() {
+    let server = MockServer::start(|head| {
+        let path = head.split_whitespace().nth(1).unwrap_or_default().to_string();
+        mock_server::response("200 OK", &[], path.as_bytes())
+    });
+    let mut manager = DownloadManager::new().unwrap();
+    for i in 0..5 {
+        let output = temp_path(&format!("shared_client_{}.txt", i));
+        manager.download(server.url(&format!("/file_{}", i)), &output);
+        while manager.has_active() {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(manager.get_download(&output).unwrap().is_successful());
+        assert_eq!(fs::read_to_string(&output).unwrap(), format!("/file_{}", i));
+        fs::remove_file(&output).unwrap();
+    }
+    assert_eq!(server.requests().len(), 5);
+    assert_eq!(server.connections(), 1);
+}
+
+/// Tests if downloads of empty bodies succeed with and without a content length.
+#[test]
+fn test_download_empty_body() {
+    let server = MockServer::start(|head| {
+        if head.starts_with("GET /unannounced ") {
+            b"HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n".to_vec()
+        } else {
+            mock_server::response("200 OK", &[], b"")
+        }
+    });
+    let announced = temp_path("empty_body_announced.txt");
+    let unannounced = temp_path("empty_body_unannounced.txt");
+    let mut manager = DownloadManager::new().unwrap();
+    manager.download(server.url("/announced"), &announced);
+    manager.download(server.url("/unannounced"), &unannounced);
+    while manager.has_active() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    for output in [&announced, &unannounced] {
+        let download = manager.get_download(output).unwrap();
+        assert!(download.is_successful());
+        assert_eq!(download.get_downloaded_size(), 0);
+        assert_eq!(download.get_total_size(), Some(0));
+        assert_eq!(download.progress(), Some(1.0));
+        assert_eq!(download.eta(), None);
+        assert_eq!(fs::metadata(output).unwrap().len(), 0);
+        fs::remove_file(output).unwrap();
+    }
+}
+
+/// Tests if the output path of a download is resolved from the template.
+#[test]
+fn test_download_templated() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"templated"));
+    let directory = temp_path("templated");
+    let mut manager = DownloadManager::new().unwrap();
+    let output = manager
+        .download_templated(server.url("/images/card.png"), &directory, "{host}/{filename}")
+        .unwrap();
+    assert_eq!(output, directory.join("127.0.0.1").join("card.png"));
+    while manager.has_active() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(manager.get_download(&output).unwrap().is_successful());
+    assert_eq!(fs::read_to_string(&output).unwrap(), "templated");
+    fs::remove_dir_all(&directory).unwrap();
+    let url = reqwest::Url::parse("https://example.com/sets/MH2.json.gz").unwrap();
+    assert_eq!(resolve_template(&url, "{stem}-copy.{ext}").unwrap(), "MH2.json-copy.gz");
+    assert!(resolve_template(&url, "{size}").is_err());
+    assert!(resolve_template(&url, "{filename").is_err());
+    let url = reqwest::Url::parse("https://example.com/sets/").unwrap();
+    assert!(resolve_template(&url, "{filename}").is_err());
+    assert!(manager.download_templated("https://example.com/", &directory, "{path}").is_err());
+    assert_eq!(manager.size(), 1);
+}
+
+/// Tests if the start and end of a download are recorded.
+#[test]
+fn test_download_duration() {
+    let server = MockServer::start(|_| mock_server::response("200 OK", &[], b"duration"));
+    let output = temp_path("download_duration.txt");
+    let before = std::time::SystemTime::now();
+    let mut manager = DownloadManager::new().unwrap();
+    manager.download(server.url("/file"), &output);
+    while manager.has_active() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let download = manager.get_download(&output).unwrap();
+    assert!(download.is_successful());
+    assert!(download.started_at().unwrap() >= before);
+    assert!(download.finished_at().unwrap() >= download.started_at().unwrap());
+    assert!(download.duration().is_some());
+    fs::remove_file(&output).unwrap();
+}
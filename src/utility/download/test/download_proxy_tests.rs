@@ -0,0 +1,84 @@
+use super::*;
+
+#[test]
+fn test_get_total_size() {
+    let download = new_download(DownloadStatus::Running);
+    let proxy = DownloadProxy {
+        download: Arc::clone(&download),
+    };
+    assert_eq!(proxy.get_total_size(), None);
+    download.lock().total_size = Some(4096);
+    assert_eq!(proxy.get_total_size(), Some(4096));
+}
+
+#[test]
+fn test_eta() {
+    let download = new_download(DownloadStatus::Running);
+    let proxy = DownloadProxy {
+        download: Arc::clone(&download),
+    };
+    // Neither the total size nor the speed are known yet.
+    assert_eq!(proxy.eta(), None);
+    download.lock().total_size = Some(3000);
+    assert_eq!(proxy.eta(), None);
+    {
+        let mut download = download.lock();
+        download.speed = 500.0;
+        download.downloaded_size = 1000;
+    }
+    assert_eq!(proxy.eta(), Some(Duration::from_secs(4)));
+    // More data than announced must not result in a negative remainder.
+    download.lock().downloaded_size = 4000;
+    assert_eq!(proxy.eta(), Some(Duration::from_secs(0)));
+    // An estimate too large to be represented is not available instead of panicking.
+    {
+        let mut download = download.lock();
+        download.speed = f64::MIN_POSITIVE;
+        download.downloaded_size = 1000;
+    }
+    assert_eq!(proxy.eta(), None);
+    download.lock().speed = 500.0;
+    download.lock().status = DownloadStatus::Successful;
+    assert_eq!(proxy.eta(), None);
+}
+
+#[test]
+fn test_progress() {
+    let download = new_download(DownloadStatus::Running);
+    let proxy = DownloadProxy {
+        download: Arc::clone(&download),
+    };
+    assert_eq!(proxy.progress(), None);
+    download.lock().total_size = Some(0);
+    assert_eq!(proxy.progress(), None);
+    {
+        let mut download = download.lock();
+        download.total_size = Some(4000);
+        download.downloaded_size = 1000;
+    }
+    assert_eq!(proxy.progress(), Some(0.25));
+    download.lock().downloaded_size = 5000;
+    assert_eq!(proxy.progress(), Some(1.0));
+    download.lock().status = DownloadStatus::Successful;
+    assert_eq!(proxy.progress(), Some(1.0));
+}
+
+#[test]
+fn test_duration() {
+    let download = new_download(DownloadStatus::Pending);
+    let proxy = DownloadProxy {
+        download: Arc::clone(&download),
+    };
+    let clock = MockClock::new();
+    let start_time = clock.system_time();
+    assert_eq!(proxy.started_at(), None);
+    assert_eq!(proxy.duration(), None);
+    download.lock().transition(DownloadStatus::Running, &clock);
+    assert_eq!(proxy.started_at(), Some(start_time));
+    assert_eq!(proxy.finished_at(), None);
+    assert_eq!(proxy.duration(), None);
+    clock.advance(Duration::from_millis(1500));
+    download.lock().transition(DownloadStatus::Successful, &clock);
+    assert_eq!(proxy.finished_at(), Some(start_time + Duration::from_millis(1500)));
+    assert_eq!(proxy.duration(), Some(Duration::from_millis(1500)));
+}
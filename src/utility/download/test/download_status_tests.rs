@@ -3,40 +3,40 @@ use super::*;
 #[test]
 fn test_is_pending() {
     let status: DownloadStatus = DownloadStatus::Pending;
-    assert_eq!(status.is_pending(), true);
+    assert!(status.is_pending());
 
     let status: DownloadStatus = DownloadStatus::Successful;
-    assert_eq!(status.is_pending(), false);
+    assert!(!status.is_pending());
 
     let err = io::Error::new(io::ErrorKind::InvalidInput, "This is a test error.");
     let status: DownloadStatus = DownloadStatus::from(err);
-    assert_eq!(status.is_pending(), false);
+    assert!(!status.is_pending());
 }
 
 #[test]
 fn test_is_successful() {
     let status: DownloadStatus = DownloadStatus::Pending;
-    assert_eq!(status.is_successful(), false);
+    assert!(!status.is_successful());
 
     let status: DownloadStatus = DownloadStatus::Successful;
-    assert_eq!(status.is_successful(), true);
+    assert!(status.is_successful());
 
     let err = io::Error::new(io::ErrorKind::InvalidInput, "This is a test error.");
     let status: DownloadStatus = DownloadStatus::from(err);
-    assert_eq!(status.is_successful(), false);
+    assert!(!status.is_successful());
 }
 
 #[test]
 fn test_is_failed() {
     let status: DownloadStatus = DownloadStatus::Pending;
-    assert_eq!(status.is_failed(), false);
+    assert!(!status.is_failed());
 
     let status: DownloadStatus = DownloadStatus::Successful;
-    assert_eq!(status.is_failed(), false);
+    assert!(!status.is_failed());
 
     let err = io::Error::new(io::ErrorKind::InvalidInput, "This is a test error.");
     let status: DownloadStatus = DownloadStatus::from(err);
-    assert_eq!(status.is_failed(), true);
+    assert!(status.is_failed());
 }
 
 #[test]
@@ -0,0 +1,121 @@
+//! A minimal HTTP server answering requests with predefined raw responses,
+//! so downloads can be tested without network access.
+
+use parking_lot::Mutex;
+use reqwest::Url;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+/// The handler producing the raw response to a request.
+type Handler = dyn Fn(&str) -> Vec<u8> + Send + Sync;
+
+/// A local HTTP server running on a background thread for the lifetime of the test process.
+pub struct MockServer {
+    address: SocketAddr,
+    requests: Arc<Mutex<Vec<String>>>,
+    connections: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+    /// Starts a server answering every request with the raw response produced by the handler.
+    /// The handler receives the head of the request, that is the request line and all headers.
+    pub fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&str) -> Vec<u8> + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+        let server_requests = Arc::clone(&requests);
+        let connections = Arc::new(AtomicUsize::new(0));
+        let server_connections = Arc::clone(&connections);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                server_connections.fetch_add(1, Ordering::SeqCst);
+                let requests = Arc::clone(&server_requests);
+                let handler = Arc::clone(&handler);
+                thread::spawn(move || serve(stream, requests, handler));
+            }
+        });
+        MockServer {
+            address,
+            requests,
+            connections,
+        }
+    }
+
+    /// Returns the URL of the specified path on this server.
+    pub fn url(&self, path: &str) -> Url {
+        Url::parse(&format!("http://{}{}", self.address, path)).unwrap()
+    }
+
+    /// Returns the heads of all requests received so far.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().clone()
+    }
+
+    /// Returns the number of connections accepted so far.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+}
+
+/// Answers all requests sent over the connection until the client or the response closes it.
+fn serve(stream: TcpStream, requests: Arc<Mutex<Vec<String>>>, handler: Arc<Handler>) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut stream = stream;
+    loop {
+        let mut head = String::new();
+        loop {
+            let mut line = String::new();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => return,
+                Ok(_) => {}
+            }
+            if line == "\r\n" {
+                break;
+            }
+            head.push_str(&line);
+        }
+        requests.lock().push(head.clone());
+        let response = handler(&head);
+        if stream.write_all(&response).is_err() || stream.flush().is_err() {
+            return;
+        }
+        if String::from_utf8_lossy(&response)
+            .to_lowercase()
+            .contains("connection: close")
+        {
+            return;
+        }
+    }
+}
+
+/// Returns a raw response with the specified status line, additional headers and body.
+/// A matching `Content-Length` header is added automatically.
+pub fn response(status: &str, headers: &[(&str, &str)], body: &[u8]) -> Vec<u8> {
+    let mut response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\n", status, body.len());
+    for (name, value) in headers {
+        response.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    response.push_str("\r\n");
+    let mut response = response.into_bytes();
+    response.extend_from_slice(body);
+    response
+}
+
+/// Returns a raw response announcing a body of the specified length, but closing
+/// the connection after sending only the specified part of it.
+pub fn truncated_response(announced_length: usize, body: &[u8]) -> Vec<u8> {
+    let mut response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        announced_length
+    )
+    .into_bytes();
+    response.extend_from_slice(body);
+    response
+}
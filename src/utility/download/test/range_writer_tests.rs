@@ -0,0 +1,15 @@
+use super::*;
+
+#[test]
+fn test_write_range() {
+    let path = temp_path("range_writer");
+    let mut writer = RangeWriter::new(File::create(&path).unwrap());
+    writer.write_range(8, b"ranges").unwrap();
+    assert_eq!(writer.written(), 6);
+    writer.write_range(0, b"out-of-").unwrap();
+    writer.write_range(7, b"o").unwrap();
+    assert_eq!(writer.written(), 14);
+    drop(writer.into_inner());
+    assert_eq!(fs::read(&path).unwrap(), b"out-of-oranges");
+    fs::remove_file(&path).unwrap();
+}
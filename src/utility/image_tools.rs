@@ -2,10 +2,13 @@
 //! image manipulation.
 
 extern crate image;
+extern crate rayon;
 
 use core::borrow::Borrow;
 use core::fmt::{Debug, Display};
-use image::GenericImageView;
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView, RgbaImage};
+use rayon::prelude::*;
 use std::num::NonZeroU32;
 use SplitMode::*;
 
@@ -64,9 +67,9 @@ where
     }
 }
 
-impl Into<(u32, u32)> for ImagePoint {
-    fn into(self) -> (u32, u32) {
-        (self.x, self.y)
+impl From<ImagePoint> for (u32, u32) {
+    fn from(point: ImagePoint) -> Self {
+        (point.x, point.y)
     }
 }
 
@@ -76,15 +79,32 @@ impl Display for ImagePoint {
     }
 }
 
+/// The `Anchor` enum defines the corner of an image a grid of sub-images
+/// is aligned to.
+#[derive(Debug, Hash, PartialEq, Eq, Default, Clone, Copy)]
+pub enum Anchor {
+    /// The grid starts at the bottom left corner.
+    BottomLeft,
+    /// The grid starts at the bottom right corner.
+    BottomRight,
+    /// The grid starts at the top left corner.
+    #[default]
+    TopLeft,
+    /// The grid starts at the top right corner.
+    TopRight,
+}
+
 /// The `SplitMode` enum contains all possible modes of splitting a image into
 /// subimages of a defined size.
 //#[non_exhaustive]
+#[derive(Default)]
 pub enum SplitMode {
     /// A mode to producing overlapping sub images at the left and bottom edges
     /// if there is no way of perfectly splitting the image.
     EdgeOverlapBottomLeftMode,
     /// A mode to producing overlapping sub images at the right and bottom edges
     /// if there is no way of perfectly splitting the image.
+    #[default]
     EdgeOverlapBottomRightMode,
     /// A mode to producing overlapping sub images at the left and top edges
     /// if there is no way of perfectly splitting the image.
@@ -92,6 +112,31 @@ pub enum SplitMode {
     /// A mode to producing overlapping sub images at the right and top edges
     /// if there is no way of perfectly splitting the image.
     EdgeOverlapTopRightMode,
+    /// A mode producing sub images that overlap their neighbours by a fixed
+    /// number of pixels. The sub images are aligned to the `anchor` corner, so
+    /// if there is no way of perfectly splitting the image, additional overlap
+    /// is produced at the edges opposite to the `anchor`.
+    ///
+    /// If the `overlap` is not smaller than the size of the sub images no sub
+    /// images are produced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::num::NonZeroU32;
+    /// use phyrexian_library::utility::image_tools::{Anchor, SplitMode, SplitableImageExt};
+    /// use image::DynamicImage;
+    ///
+    /// let mode = SplitMode::FixedOverlap {
+    ///     overlap: 10,
+    ///     anchor: Anchor::TopLeft,
+    /// };
+    /// let mut image = DynamicImage::new_rgba8(100, 100);
+    /// let split_length = NonZeroU32::new(40).unwrap();
+    /// let split_images = image.split_into(split_length, split_length, mode);
+    /// assert_eq!(split_images.len(), 9);
+    /// ```
+    FixedOverlap { overlap: u32, anchor: Anchor },
     /// A custom splitting mode.
     ///
     /// # Example
@@ -122,6 +167,11 @@ impl Debug for SplitMode {
             EdgeOverlapBottomRightMode => write!(f, "EdgeOverlapBottomRightMode"),
             EdgeOverlapTopLeftMode => write!(f, "EdgeOverlapTopLeftMode"),
             EdgeOverlapTopRightMode => write!(f, "EdgeOverlapTopRightMode"),
+            FixedOverlap { overlap, anchor } => write!(
+                f,
+                "FixedOverlap {{ overlap: {:?}, anchor: {:?} }}",
+                overlap, anchor
+            ),
             CustomMode(_) => write!(f, "CustomMode"),
         }
     }
@@ -134,6 +184,11 @@ impl Display for SplitMode {
             EdgeOverlapBottomRightMode => write!(f, "Split mode: overlap at bottom and right edge"),
             EdgeOverlapTopLeftMode => write!(f, "Split mode: overlap at top and left edge"),
             EdgeOverlapTopRightMode => write!(f, "Split mode: overlap at top and right edge"),
+            FixedOverlap { overlap, anchor } => write!(
+                f,
+                "Split mode: fixed overlap of {} pixels anchored at {:?}",
+                overlap, anchor
+            ),
             CustomMode(_) => write!(f, "Split mode: custom splitting"),
         }
     }
@@ -173,6 +228,27 @@ impl SplitMode {
                 &split_range_align_end(image_width, split_width),
                 &split_range_align_start(image_height, split_height),
             ),
+            FixedOverlap { overlap, anchor } => {
+                let (x_range, y_range) = match anchor {
+                    Anchor::BottomLeft => (
+                        split_range_overlap_align_end(image_width, split_width, *overlap),
+                        split_range_overlap_align_start(image_height, split_height, *overlap),
+                    ),
+                    Anchor::BottomRight => (
+                        split_range_overlap_align_start(image_width, split_width, *overlap),
+                        split_range_overlap_align_start(image_height, split_height, *overlap),
+                    ),
+                    Anchor::TopLeft => (
+                        split_range_overlap_align_end(image_width, split_width, *overlap),
+                        split_range_overlap_align_end(image_height, split_height, *overlap),
+                    ),
+                    Anchor::TopRight => (
+                        split_range_overlap_align_start(image_width, split_width, *overlap),
+                        split_range_overlap_align_end(image_height, split_height, *overlap),
+                    ),
+                };
+                combine_coordinates(&x_range, &y_range)
+            },
             CustomMode(custom_function) => {
                 custom_function(image_width, image_height, split_width, split_height)
             }
@@ -180,17 +256,15 @@ impl SplitMode {
     }
 }
 
-impl Default for SplitMode {
-    fn default() -> Self {
-        EdgeOverlapBottomRightMode
-    }
-}
-
 pub trait SplitableImageExt
 where
     Self: image::GenericImage + Sized,
 {
     fn split_into(&mut self, width: NonZeroU32, height: NonZeroU32, mode: SplitMode) -> Vec<Self>;
+
+    fn par_split_into(&self, width: NonZeroU32, height: NonZeroU32, mode: SplitMode) -> Vec<Self>;
+
+    fn split_into_grid(&mut self, columns: NonZeroU32, rows: NonZeroU32) -> Vec<Self>;
 }
 
 impl SplitableImageExt for image::DynamicImage {
@@ -213,6 +287,127 @@ impl SplitableImageExt for image::DynamicImage {
             Vec::new()
         }
     }
+
+    /// Splits the image into sub-images of the specified dimension in parallel.
+    /// The sub-images are returned in the same order as by [`split_into`].
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the sub-images.
+    /// * `height` - The height of the sub-images.
+    /// * `SplitMode` - The mode of image splitting.
+    ///
+    /// [`split_into`]: #method.split_into
+    fn par_split_into(&self, width: NonZeroU32, height: NonZeroU32, mode: SplitMode) -> Vec<Self> {
+        let (width_u, height_u) = (width.get(), height.get());
+        // Only split images if the image can be split.
+        if self.height() >= height_u && self.width() >= width_u {
+            mode.get_starts(self.width(), self.height(), width, height)
+                .par_iter()
+                .map(|start| self.crop_imm(start.x(), start.y(), width_u, height_u))
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Splits the image into a grid of the specified number of columns and rows.
+    /// The sub-images are returned in row-major order. If the image cannot be split
+    /// evenly the remaining pixels are added to the sub-images of the last column and row.
+    ///
+    /// If the image is narrower than the number of columns or lower than the number
+    /// of rows an empty vector is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The number of columns of the grid.
+    /// * `rows` - The number of rows of the grid.
+    fn split_into_grid(&mut self, columns: NonZeroU32, rows: NonZeroU32) -> Vec<Self> {
+        let x_cells = split_range_grid(self.width(), columns);
+        let y_cells = split_range_grid(self.height(), rows);
+        let mut sub_images = Vec::with_capacity(x_cells.len() * y_cells.len());
+        for (y, height) in &y_cells {
+            for (x, width) in &x_cells {
+                sub_images.push(self.crop(*x, *y, *width, *height));
+            }
+        }
+        sub_images
+    }
+}
+
+/// Returns a thumbnail of the image whose longest side is scaled to the
+/// specified length while preserving the aspect ratio. Images that already fit
+/// into the specified length are not upscaled but returned unchanged.
+///
+/// # Arguments
+///
+/// * `image` - The image to create a thumbnail of.
+/// * `max_dimension` - The maximum length of the longest side of the thumbnail.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use phyrexian_library::utility::image_tools::thumbnail;
+/// use image::{DynamicImage, GenericImageView};
+///
+/// let image = DynamicImage::new_rgba8(400, 200);
+/// let thumbnail = thumbnail(&image, NonZeroU32::new(100).unwrap());
+/// assert_eq!(thumbnail.dimensions(), (100, 50));
+/// ```
+pub fn thumbnail(image: &DynamicImage, max_dimension: NonZeroU32) -> DynamicImage {
+    let max_dimension = max_dimension.get();
+    if image.width() <= max_dimension && image.height() <= max_dimension {
+        image.clone()
+    } else {
+        image.resize(max_dimension, max_dimension, FilterType::Lanczos3)
+    }
+}
+
+/// Converts the image into packed RGBA pixels with 8 bits per channel in
+/// row-major order. Returns the pixels together with the width and height of
+/// the image.
+///
+/// # Arguments
+///
+/// * `image` - The image to convert.
+pub fn to_rgba_bytes(image: &DynamicImage) -> (Vec<u8>, u32, u32) {
+    let rgba = image.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    (rgba.into_raw(), width, height)
+}
+
+/// Creates an image from packed RGBA pixels with 8 bits per channel in
+/// row-major order as returned by [`to_rgba_bytes`].
+///
+/// # Arguments
+///
+/// * `bytes` - The packed RGBA pixels.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+///
+/// # Errors
+///
+/// Returns an error if the number of bytes does not match the dimensions.
+///
+/// [`to_rgba_bytes`]: ./fn.to_rgba_bytes.html
+pub fn from_rgba_bytes(bytes: &[u8], width: u32, height: u32) -> Result<DynamicImage, String> {
+    let expected = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|pixels| pixels.checked_mul(4));
+    if expected != Some(bytes.len()) {
+        return Err(format!(
+            "{} bytes do not match an RGBA image of {}x{} pixels.",
+            bytes.len(),
+            width,
+            height
+        ));
+    }
+    RgbaImage::from_raw(width, height, bytes.to_vec())
+        .map(DynamicImage::ImageRgba8)
+        .ok_or_else(|| {
+            format!("An RGBA image of {}x{} pixels could not be created.", width, height)
+        })
 }
 
 /// Splits the specified range into parts of the defined length.
@@ -225,18 +420,9 @@ impl SplitableImageExt for image::DynamicImage {
 ///
 /// * `original` - A number representing a continous range.
 /// * `split` - The length of the parts to split the specified range into.
-/// this cannot be zero.
+///   this cannot be zero.
 fn split_range_align_end(original: u32, split: NonZeroU32) -> Vec<u32> {
-    let split = split.get();
-    if original < split {
-        Vec::new()
-    } else {
-        let mut range: Vec<u32> = (0..(original / split)).map(|h| h * split).collect();
-        if original % split != 0 {
-            range.push(original - split);
-        }
-        range
-    }
+    split_range_overlap_align_end(original, split, 0)
 }
 
 /// Splits the specified range into parts of the defined length.
@@ -249,24 +435,92 @@ fn split_range_align_end(original: u32, split: NonZeroU32) -> Vec<u32> {
 ///
 /// * `original` - A number representing a continous range.
 /// * `split` - The length of the parts to split the specified range into.
-/// this cannot be zero.
+///   this cannot be zero.
 fn split_range_align_start(original: u32, split: NonZeroU32) -> Vec<u32> {
+    split_range_overlap_align_start(original, split, 0)
+}
+
+/// Splits the specified range into parts of the defined length, where adjacent
+/// parts overlap by the defined amount. Additional overlapping may happen at the
+/// end of the range.
+///
+/// If the defined `split` length is longer than the `original` range or the
+/// `overlap` is not shorter than the `split` length an empty vector is returned.
+///
+/// # Arguments
+///
+/// * `original` - A number representing a continous range.
+/// * `split` - The length of the parts to split the specified range into.
+///   this cannot be zero.
+/// * `overlap` - The length by which adjacent parts overlap.
+// `u32::is_multiple_of` is only available since Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+fn split_range_overlap_align_end(original: u32, split: NonZeroU32, overlap: u32) -> Vec<u32> {
     let split = split.get();
-    if original < split {
+    if original < split || overlap >= split {
         Vec::new()
     } else {
-        let overlap = original % split;
-        let mut range: Vec<u32> = (0..(original / split))
-            .map(|i| i * split)
-            .map(|i| i + overlap)
-            .collect();
-        if overlap != 0 {
-            range.insert(0, 0);
+        let step = split - overlap;
+        let last = original - split;
+        let mut range: Vec<u32> = (0..=(last / step)).map(|i| i * step).collect();
+        if last % step != 0 {
+            range.push(last);
         }
         range
     }
 }
 
+/// Splits the specified range into parts of the defined length, where adjacent
+/// parts overlap by the defined amount. Additional overlapping may happen at the
+/// start of the range.
+///
+/// If the defined `split` length is longer than the `original` range or the
+/// `overlap` is not shorter than the `split` length an empty vector is returned.
+///
+/// # Arguments
+///
+/// * `original` - A number representing a continous range.
+/// * `split` - The length of the parts to split the specified range into.
+///   this cannot be zero.
+/// * `overlap` - The length by which adjacent parts overlap.
+fn split_range_overlap_align_start(original: u32, split: NonZeroU32, overlap: u32) -> Vec<u32> {
+    split_range_overlap_align_end(original, split, overlap)
+        .iter()
+        .rev()
+        .map(|start| original - split.get() - start)
+        .collect()
+}
+
+/// Splits the specified range into the defined number of parts of equal length.
+/// The remainder is added to the last part. Returns the start and length of
+/// every part.
+///
+/// If the `original` range is shorter than the defined number of `parts` an
+/// empty vector is returned.
+///
+/// # Arguments
+///
+/// * `original` - A number representing a continous range.
+/// * `parts` - The number of parts to split the specified range into.
+fn split_range_grid(original: u32, parts: NonZeroU32) -> Vec<(u32, u32)> {
+    let parts = parts.get();
+    if original < parts {
+        Vec::new()
+    } else {
+        let length = original / parts;
+        (0..parts)
+            .map(|i| {
+                let start = i * length;
+                if i == parts - 1 {
+                    (start, original - start)
+                } else {
+                    (start, length)
+                }
+            })
+            .collect()
+    }
+}
+
 /// Combines the coordinates into [`ImagePoint`]s by forming every
 /// possible x-y-pair.
 ///
@@ -299,6 +553,90 @@ mod tests {
         assert_eq!(point.y(), y);
     }
 
+    #[test]
+    fn test_par_split_into() {
+        let mut image = image::DynamicImage::ImageRgba8(image::ImageBuffer::from_fn(
+            100,
+            70,
+            |x, y| image::Rgba([x as u8, y as u8, (x * y) as u8, 255]),
+        ));
+        let (width, height) = (NonZeroU32::new(32).unwrap(), NonZeroU32::new(16).unwrap());
+        let parallel = image.par_split_into(width, height, SplitMode::default());
+        let sequential = image.split_into(width, height, SplitMode::default());
+        assert_eq!(parallel.len(), 20);
+        assert_eq!(parallel, sequential);
+        // Test images that are too small to be split.
+        let large = NonZeroU32::new(128).unwrap();
+        assert!(image.par_split_into(large, height, SplitMode::default()).is_empty());
+    }
+
+    #[test]
+    fn test_split_into_grid() {
+        let mut image = image::DynamicImage::new_rgba8(300, 200);
+        let grid = image.split_into_grid(NonZeroU32::new(3).unwrap(), NonZeroU32::new(2).unwrap());
+        assert_eq!(grid.len(), 6);
+        for sub_image in &grid {
+            assert_eq!(sub_image.dimensions(), (100, 100));
+        }
+        // Test remainder pixels being assigned to the last column and row.
+        let mut image = image::DynamicImage::ImageRgba8(image::ImageBuffer::from_fn(
+            10,
+            7,
+            |x, y| image::Rgba([x as u8, y as u8, 0, 255]),
+        ));
+        let grid = image.split_into_grid(NonZeroU32::new(3).unwrap(), NonZeroU32::new(2).unwrap());
+        let dimensions: Vec<(u32, u32)> = grid.iter().map(|sub| sub.dimensions()).collect();
+        assert_eq!(dimensions, vec!((3, 3), (3, 3), (4, 3), (3, 4), (3, 4), (4, 4)));
+        // Test the row-major order.
+        assert_eq!(grid[1].get_pixel(0, 0), image::Rgba([3, 0, 0, 255]));
+        assert_eq!(grid[3].get_pixel(0, 0), image::Rgba([0, 3, 0, 255]));
+        // Test images that are too small to be split.
+        let too_many = NonZeroU32::new(11).unwrap();
+        assert!(image.split_into_grid(too_many, NonZeroU32::new(1).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_thumbnail() {
+        let max_dimension = NonZeroU32::new(100).unwrap();
+        let landscape = image::DynamicImage::new_rgba8(400, 200);
+        assert_eq!(thumbnail(&landscape, max_dimension).dimensions(), (100, 50));
+        let portrait = image::DynamicImage::new_rgba8(150, 300);
+        assert_eq!(thumbnail(&portrait, max_dimension).dimensions(), (50, 100));
+        // Test small images not being upscaled.
+        let small = image::DynamicImage::ImageRgba8(image::ImageBuffer::from_fn(
+            50,
+            50,
+            |x, y| image::Rgba([x as u8, y as u8, 0, 255]),
+        ));
+        assert_eq!(thumbnail(&small, max_dimension), small);
+    }
+
+    #[test]
+    fn test_rgba_bytes() {
+        let image = image::DynamicImage::ImageRgba8(image::ImageBuffer::from_fn(
+            3,
+            2,
+            |x, y| image::Rgba([x as u8, y as u8, (x + y) as u8, 200]),
+        ));
+        let (bytes, width, height) = to_rgba_bytes(&image);
+        assert_eq!((width, height), (3, 2));
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(&bytes[4..8], &[1, 0, 1, 200]);
+        assert_eq!(from_rgba_bytes(&bytes, width, height).unwrap(), image);
+        // Test a mismatch between the number of bytes and the dimensions.
+        assert!(from_rgba_bytes(&bytes, 2, 2).is_err());
+        assert!(from_rgba_bytes(&bytes[1..], width, height).is_err());
+        assert!(from_rgba_bytes(&bytes, u32::MAX, u32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_split_range_grid() {
+        let parts = NonZeroU32::new(3).unwrap();
+        assert_eq!(split_range_grid(300, parts), vec!((0, 100), (100, 100), (200, 100)));
+        assert_eq!(split_range_grid(10, parts), vec!((0, 3), (3, 3), (6, 4)));
+        assert_eq!(split_range_grid(2, parts), Vec::<(u32, u32)>::new());
+    }
+
     #[test]
     fn test_split_align_start() {
         // Test zero input length.
@@ -347,6 +685,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_split_overlap() {
+        let split = NonZeroU32::new(40).unwrap();
+        // Test a perfect split.
+        assert_eq!(split_range_overlap_align_end(100, split, 10), vec!(0, 30, 60));
+        assert_eq!(split_range_overlap_align_start(100, split, 10), vec!(0, 30, 60));
+        // Test additional overlap at the edges.
+        assert_eq!(split_range_overlap_align_end(110, split, 10), vec!(0, 30, 60, 70));
+        assert_eq!(split_range_overlap_align_start(110, split, 10), vec!(0, 10, 40, 70));
+        // Test an overlap not smaller than the split length.
+        assert_eq!(split_range_overlap_align_end(100, split, 40), Vec::<u32>::new());
+        assert_eq!(split_range_overlap_align_start(100, split, 50), Vec::<u32>::new());
+        // Test input length smaller than split length.
+        assert_eq!(split_range_overlap_align_end(30, split, 10), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_fixed_overlap_starts() {
+        let split = NonZeroU32::new(40).unwrap();
+        let mode = SplitMode::FixedOverlap {
+            overlap: 10,
+            anchor: Anchor::TopLeft,
+        };
+        let starts = mode.get_starts(100, 100, split, split);
+        let expected: Vec<ImagePoint> = [0, 30, 60]
+            .iter()
+            .flat_map(|x| [0, 30, 60].iter().map(move |y| ImagePoint::new(x, y)))
+            .collect();
+        assert_eq!(starts, expected);
+        let mode = SplitMode::FixedOverlap {
+            overlap: 10,
+            anchor: Anchor::BottomRight,
+        };
+        let starts = mode.get_starts(110, 100, split, split);
+        assert_eq!(starts.len(), 12);
+        assert_eq!(starts[0], ImagePoint::new(0, 0));
+        assert_eq!(starts[3], ImagePoint::new(10, 0));
+        assert_eq!(starts[11], ImagePoint::new(70, 60));
+        let mode = SplitMode::FixedOverlap {
+            overlap: 50,
+            anchor: Anchor::TopLeft,
+        };
+        assert!(mode.get_starts(100, 100, split, split).is_empty());
+    }
+
     #[test]
     fn test_combine_coordinates() {
         let x = vec![7, 24987, 78];
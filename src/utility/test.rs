@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn test_natural_cmp_numbers() {
+    assert_eq!(natural_cmp("2", "10"), Ordering::Less);
+    assert_eq!(natural_cmp("10", "2"), Ordering::Greater);
+    assert_eq!(natural_cmp("99", "100"), Ordering::Less);
+    assert_eq!(natural_cmp("011", "10"), Ordering::Greater);
+    assert_eq!(natural_cmp("01", "1"), Ordering::Less);
+}
+
+#[test]
+fn test_natural_cmp_suffixes() {
+    assert_eq!(natural_cmp("12", "12a"), Ordering::Less);
+    assert_eq!(natural_cmp("12a", "12b"), Ordering::Less);
+    assert_eq!(natural_cmp("12b", "100"), Ordering::Less);
+    assert_eq!(natural_cmp("a2", "a10"), Ordering::Less);
+    assert_eq!(natural_cmp("1", "★1"), Ordering::Less);
+}
+
+#[test]
+fn test_natural_cmp_equal() {
+    assert_eq!(natural_cmp("", ""), Ordering::Equal);
+    assert_eq!(natural_cmp("12", "12"), Ordering::Equal);
+    assert_eq!(natural_cmp("12a", "12a"), Ordering::Equal);
+}